@@ -0,0 +1,332 @@
+use chrono::{Datelike, NaiveDate, Utc, Weekday};
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use taking_turns::business::affectations::{AbsencePolicy, Affectation};
+use taking_turns::business::csv::{export_csv, CsvOptions};
+use taking_turns::business::date_range::DateRange;
+use taking_turns::business::ics::{export_ics, IcsExportOptions};
+use taking_turns::business::locale::Locale;
+use taking_turns::business::name::Name;
+use taking_turns::business::person::{Person, PersonId};
+use taking_turns::business::project::Project;
+use taking_turns::business::schedule::{Plan, Schedule};
+use taking_turns::business::weekday_filter::WeekDayFilter;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "taking-turns-cli",
+    about = "Print who takes their turn on each day of a period"
+)]
+struct Arguments {
+    #[arg(long, help = "Project file to start from")]
+    project: Option<PathBuf>,
+    #[arg(long = "name", help = "Person taking turns, in order (repeatable)")]
+    names: Vec<String>,
+    #[arg(long, requires = "to", help = "First day of the period")]
+    from: Option<String>,
+    #[arg(long, requires = "from", help = "Last day of the period")]
+    to: Option<String>,
+    #[arg(
+        long = "weekday",
+        value_parser = parse_weekday,
+        help = "Day of the week taking part in the rotation (repeatable)"
+    )]
+    weekdays: Vec<Weekday>,
+    #[arg(
+        long = "exclude",
+        help = "Excluded day or period, e.g. 2025-02-10..2025-02-14 (repeatable)"
+    )]
+    exclusions: Vec<String>,
+    #[arg(
+        long,
+        help = "Skip absent people instead of having the next one substitute"
+    )]
+    skip_absent: bool,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    #[arg(long, value_enum, default_value_t = Language::Fr)]
+    locale: Language,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+    Ics,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Language {
+    Fr,
+    En,
+}
+
+impl From<Language> for Locale {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::Fr => Locale::French,
+            Language::En => Locale::English,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Turn<'a> {
+    date: NaiveDate,
+    name: &'a Name,
+    people: Vec<Member<'a>>,
+}
+
+#[derive(Serialize)]
+struct Member<'a> {
+    id: PersonId,
+    name: &'a Name,
+}
+
+impl<'a> From<&'a Affectation> for Turn<'a> {
+    fn from(affectation: &'a Affectation) -> Self {
+        Turn {
+            date: affectation.date,
+            name: affectation.assignee.name(),
+            people: affectation
+                .assignee
+                .people()
+                .into_iter()
+                .map(|person| Member {
+                    id: person.id(),
+                    name: person.name(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, String> {
+    value.parse::<Weekday>().or_else(|_| {
+        [Locale::French, Locale::English]
+            .iter()
+            .flat_map(|locale| {
+                (0..7).map(|index| {
+                    let day = Weekday::try_from(index).expect("There are seven weekdays");
+                    (locale.weekday_name(day), day)
+                })
+            })
+            .find(|(name, _)| name.eq_ignore_ascii_case(value))
+            .map(|(_, day)| day)
+            .ok_or(format!("unknown weekday {value}"))
+    })
+}
+
+fn project(arguments: &Arguments) -> Result<Project, String> {
+    let locale = Locale::from(arguments.locale);
+    let mut project = match &arguments.project {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .map_err(|error| format!("{}: {error}", path.display()))?;
+            Project::from_json(&json).map_err(|error| format!("{}: {error}", path.display()))?
+        }
+        None => Project::default(),
+    };
+    for name in &arguments.names {
+        let name = Name::try_from(name.clone()).map_err(|error| error.to_string())?;
+        let person = Person::new(PersonId::next_available(&project.people), name);
+        project.people = project
+            .people
+            .insert(person)
+            .map_err(|error| error.to_string())?;
+    }
+    if let (Some(from), Some(to)) = (&arguments.from, &arguments.to) {
+        project.range =
+            Some(DateRange::parse(from, to, locale).map_err(|error| error.to_string())?);
+    }
+    if !arguments.weekdays.is_empty() {
+        project.weekday_filter = WeekDayFilter::from(arguments.weekdays.clone());
+    }
+    for exclusion in &arguments.exclusions {
+        let (from, to) = exclusion.split_once("..").unwrap_or((exclusion, exclusion));
+        let period =
+            DateRange::parse(from, to, locale).map_err(|error| format!("{exclusion}: {error}"))?;
+        project.excluded_periods = project.excluded_periods.insert(period);
+    }
+    if arguments.skip_absent {
+        project.absence_policy = AbsencePolicy::Skip;
+    }
+    if project.range.is_none() {
+        return Err("a period is required, either --from and --to or a project file".to_string());
+    }
+    Ok(project)
+}
+
+fn text(affectations: &[Affectation], locale: Locale) -> String {
+    affectations
+        .iter()
+        .map(|affectation| {
+            format!(
+                "{} {} : {}\n",
+                locale.weekday_name(affectation.date.weekday()),
+                locale.format_date(affectation.date),
+                affectation.assignee
+            )
+        })
+        .collect()
+}
+
+fn json(affectations: &[Affectation]) -> String {
+    let turns: Vec<Turn> = affectations.iter().map(Turn::from).collect();
+    serde_json::to_string_pretty(&turns).expect("Turns are serializable") + "\n"
+}
+
+fn run(arguments: &Arguments) -> Result<String, String> {
+    let project = project(arguments)?;
+    let locale = Locale::from(arguments.locale);
+    let Plan {
+        affectations,
+        warnings,
+    } = Schedule::from(&project).plan();
+    for warning in warnings {
+        eprintln!("warning: {warning}");
+    }
+    let output = match arguments.format {
+        Format::Text => text(&affectations, locale),
+        Format::Csv => {
+            let options = CsvOptions {
+                locale,
+                ..CsvOptions::default()
+            };
+            export_csv(&affectations, &options).map_err(|error| error.to_string())?
+        }
+        Format::Json => json(&affectations),
+        Format::Ics => export_ics(
+            &affectations,
+            project.id,
+            &IcsExportOptions::default(),
+            Utc::now(),
+        ),
+    };
+    Ok(output)
+}
+
+fn main() -> ExitCode {
+    match run(&Arguments::parse()) {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod cli_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+    use taking_turns::business::affectations::Assignee;
+
+    fn arguments(arguments: &[&str]) -> Arguments {
+        Arguments::try_parse_from(["taking-turns-cli"].iter().chain(arguments))
+            .expect("Test arguments should be valid")
+    }
+
+    fn week(extra: &[&str]) -> Arguments {
+        let mut all = vec![
+            "--name",
+            "Xavier",
+            "--name",
+            "Merve",
+            "--from",
+            "2025-01-06",
+            "--to",
+            "2025-01-10",
+        ];
+        all.extend(extra);
+        arguments(&all)
+    }
+
+    #[rstest]
+    fn build_a_project_from_the_arguments() {
+        let project = project(&week(&["--weekday", "lundi", "--weekday", "Fri"])).unwrap();
+        assert_that!(
+            project
+                .people
+                .iter()
+                .map(|person| person.name().to_string())
+                .collect::<Vec<_>>(),
+            container_eq(["Xavier".to_string(), "Merve".to_string()])
+        );
+        assert_that!(
+            project.range,
+            some(eq(
+                DateRange::try_from(("2025-01-06", "2025-01-10")).unwrap()
+            ))
+        );
+        assert_that!(
+            project.weekday_filter,
+            eq(&WeekDayFilter::from(vec![Weekday::Mon, Weekday::Fri]))
+        )
+    }
+
+    #[rstest]
+    #[case::period("2025-01-07..2025-01-08", ("2025-01-07", "2025-01-08"))]
+    #[case::single_day("2025-01-07", ("2025-01-07", "2025-01-07"))]
+    fn exclude_days_and_periods(#[case] exclusion: &str, #[case] expected: (&str, &str)) {
+        let project = project(&week(&["--exclude", exclusion])).unwrap();
+        assert_that!(
+            Vec::<DateRange>::from(project.excluded_periods),
+            container_eq([DateRange::try_from(expected).unwrap()])
+        )
+    }
+
+    #[rstest]
+    fn print_only_ids_and_names_as_json() {
+        let xavier = Person::new(PersonId::from(1), "Xavier".to_string().try_into().unwrap())
+            .with_email(Some(String::from("xavier@example.com").try_into().unwrap()));
+        let affectation = Affectation {
+            date: NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(),
+            assignee: Assignee::Person(xavier),
+        };
+        let json: serde_json::Value = serde_json::from_str(&json(&[affectation])).unwrap();
+        assert_that!(
+            json,
+            eq(&serde_json::json!([{
+                "date": "2025-01-06",
+                "name": "Xavier",
+                "people": [{"id": 1, "name": "Xavier"}]
+            }]))
+        )
+    }
+
+    #[rstest]
+    #[case::no_period(arguments(&["--name", "Xavier"]))]
+    #[case::invalid_name(week(&["--name", " "]))]
+    #[case::duplicate_name(week(&["--name", "xavier"]))]
+    #[case::invalid_exclusion(week(&["--exclude", "2025-01-08..2025-01-07"]))]
+    #[case::missing_project(arguments(&["--project", "/nonexistent/project.json"]))]
+    fn fail_on_invalid_settings(#[case] arguments: Arguments) {
+        assert_that!(run(&arguments), err(anything()))
+    }
+
+    #[rstest]
+    #[case::from_without_to(&["--from", "2025-01-06"])]
+    #[case::unknown_weekday(&["--weekday", "someday"])]
+    #[case::unknown_format(&["--format", "xml"])]
+    fn reject_malformed_arguments(#[case] arguments: &[&str]) {
+        assert_that!(
+            Arguments::try_parse_from(["taking-turns-cli"].iter().chain(arguments)),
+            err(anything())
+        )
+    }
+
+    #[rstest]
+    fn be_named_after_its_binary() {
+        use clap::CommandFactory;
+        assert_that!(Arguments::command().get_name(), eq("taking-turns-cli"))
+    }
+}
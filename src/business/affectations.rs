@@ -1,21 +1,79 @@
-use crate::business::name::{Name, Names};
+use crate::business::name::Name;
+use crate::business::person::{People, Person};
 use chrono::NaiveDate;
+use derive_more::with_trait::Display;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
-#[derive(Debug, Eq, PartialEq, derive_more::Display, Clone)]
-#[display("{date}: {name}")]
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Assignee {
+    #[display("{_0}")]
+    Person(Person),
+    #[display("{name} ({})", members.iter().map(Person::to_string).collect::<Vec<_>>().join(", "))]
+    Group { name: Name, members: Vec<Person> },
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbsencePolicy {
+    #[default]
+    Substitute,
+    Skip,
+}
+
+#[derive(Debug, Eq, PartialEq, Display, Clone, Serialize, Deserialize)]
+#[display("{date}: {assignee}")]
 pub struct Affectation {
     pub date: NaiveDate,
-    pub name: Name,
+    pub assignee: Assignee,
+}
+
+impl Assignee {
+    pub fn individuals(people: People) -> Vec<Assignee> {
+        people.into_iter().map(Assignee::Person).collect()
+    }
+
+    pub fn name(&self) -> &Name {
+        match self {
+            Assignee::Person(person) => person.name(),
+            Assignee::Group { name, .. } => name,
+        }
+    }
+
+    pub fn people(&self) -> Vec<&Person> {
+        match self {
+            Assignee::Person(person) => vec![person],
+            Assignee::Group { members, .. } => members.iter().collect(),
+        }
+    }
+
+    pub fn is_available_on(&self, date: &NaiveDate) -> bool {
+        self.people()
+            .iter()
+            .all(|person| person.is_available_on(date))
+    }
 }
 
 pub fn create_affectations(
-    names: Names,
+    assignees: Vec<Assignee>,
     date_range: impl IntoIterator<Item = NaiveDate>,
+    absence_policy: AbsencePolicy,
 ) -> Vec<Affectation> {
+    let mut rotation = VecDeque::from(assignees);
     date_range
         .into_iter()
-        .zip(names.into_iter().cycle())
-        .map(|(date, name)| Affectation { date, name })
+        .filter_map(|date| {
+            let next = rotation
+                .iter()
+                .position(|assignee| assignee.is_available_on(&date))?;
+            let assignee = rotation.remove(next)?;
+            if absence_policy == AbsencePolicy::Skip {
+                rotation.rotate_left(next);
+            }
+            rotation.push_back(assignee.clone());
+            Some(Affectation { date, assignee })
+        })
         .collect()
 }
 
@@ -24,45 +82,144 @@ mod affectation_system_should {
     use super::*;
     use crate::business::date_range::test_helpers::date_from;
     use crate::business::date_range::DateRange;
+    use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+    use crate::business::person::test_helpers::{people, person};
+    use crate::business::person::PersonId;
     use googletest::assert_that;
     use googletest::prelude::container_eq;
     use rstest::rstest;
 
     #[rstest]
     fn affect_first_name_to_first_date() {
-        let names = vec!["Xavier".into()];
+        let people = people(&["Xavier"]);
         let date_range: DateRange = ("2025-01-01", "2025-01-01").try_into().unwrap();
-        let affectations = create_affectations(names, date_range);
+        let affectations = create_affectations(
+            Assignee::individuals(people),
+            date_range,
+            AbsencePolicy::default(),
+        );
         assert_that!(
             affectations,
             container_eq([Affectation {
                 date: date_from("2025-01-01"),
-                name: "Xavier".into()
+                assignee: Assignee::Person(person(1, "Xavier"))
             }])
         )
     }
 
     #[rstest]
     fn affect_a_name_to_each_dates_by_cycling_over_names() {
-        let names = vec!["Xavier".into(), "Merve".into()];
+        let people = people(&["Xavier", "Merve"]);
         let date_range: DateRange = ("2025-01-01", "2025-01-03").try_into().unwrap();
-        let affectations = create_affectations(names, date_range);
+        let affectations = create_affectations(
+            Assignee::individuals(people),
+            date_range,
+            AbsencePolicy::default(),
+        );
         assert_that!(
             affectations,
             container_eq([
                 Affectation {
                     date: date_from("2025-01-01"),
-                    name: "Xavier".into()
+                    assignee: Assignee::Person(person(1, "Xavier"))
                 },
                 Affectation {
                     date: date_from("2025-01-02"),
-                    name: "Merve".into()
+                    assignee: Assignee::Person(person(2, "Merve"))
                 },
                 Affectation {
                     date: date_from("2025-01-03"),
-                    name: "Xavier".into()
+                    assignee: Assignee::Person(person(1, "Xavier"))
                 }
             ])
         )
     }
+
+    #[rstest]
+    fn keep_track_of_who_is_affected() {
+        let people = people(&["Alex", "Alexandre"]);
+        let date_range: DateRange = ("2025-01-01", "2025-01-02").try_into().unwrap();
+        let affectations = create_affectations(
+            Assignee::individuals(people),
+            date_range,
+            AbsencePolicy::default(),
+        );
+        assert_that!(
+            affectations
+                .iter()
+                .flat_map(|affectation| affectation.assignee.people())
+                .map(Person::id)
+                .collect::<Vec<_>>(),
+            container_eq([PersonId::from(1), PersonId::from(2)])
+        )
+    }
+
+    fn away(person: Person, from: &str, to: &str) -> Person {
+        let leave = DateRange::try_from((from, to)).unwrap();
+        person.with_unavailability(ExcludedPeriodsFilter::from(vec![leave]))
+    }
+
+    fn names_of(affectations: Vec<Affectation>) -> Vec<String> {
+        affectations
+            .into_iter()
+            .map(|affectation| affectation.assignee.name().to_string())
+            .collect()
+    }
+
+    #[rstest]
+    #[case::substitute(AbsencePolicy::Substitute, ["Merve", "Xavier", "Alex", "Merve"])]
+    #[case::skip(AbsencePolicy::Skip, ["Merve", "Alex", "Xavier", "Merve"])]
+    fn handle_unavailable_people_according_to_policy(
+        #[case] policy: AbsencePolicy,
+        #[case] expected: [&str; 4],
+    ) {
+        let people = vec![
+            Assignee::Person(away(person(1, "Xavier"), "2025-01-01", "2025-01-01")),
+            Assignee::Person(person(2, "Merve")),
+            Assignee::Person(person(3, "Alex")),
+        ];
+        let date_range: DateRange = ("2025-01-01", "2025-01-04").try_into().unwrap();
+        let affectations = create_affectations(people, date_range, policy);
+        assert_that!(names_of(affectations), container_eq(expected))
+    }
+
+    #[rstest]
+    fn leave_a_date_unassigned_when_nobody_is_available() {
+        let people = vec![Assignee::Person(away(
+            person(1, "Xavier"),
+            "2025-01-02",
+            "2025-01-02",
+        ))];
+        let date_range: DateRange = ("2025-01-01", "2025-01-03").try_into().unwrap();
+        let affectations = create_affectations(people, date_range, AbsencePolicy::default());
+        assert_that!(
+            affectations
+                .into_iter()
+                .map(|affectation| affectation.date)
+                .collect::<Vec<_>>(),
+            container_eq([date_from("2025-01-01"), date_from("2025-01-03")])
+        )
+    }
+
+    #[rstest]
+    fn rotate_over_groups_skipping_those_with_a_member_away() {
+        let blue = Assignee::Group {
+            name: "Team Blue".into(),
+            members: vec![
+                person(1, "Xavier"),
+                away(person(2, "Merve"), "2025-01-01", "2025-01-01"),
+            ],
+        };
+        let red = Assignee::Group {
+            name: "Team Red".into(),
+            members: vec![person(3, "Alex"), person(4, "Sam")],
+        };
+        let date_range: DateRange = ("2025-01-01", "2025-01-03").try_into().unwrap();
+        let affectations =
+            create_affectations(vec![blue, red], date_range, AbsencePolicy::Substitute);
+        assert_that!(
+            names_of(affectations),
+            container_eq(["Team Red", "Team Blue", "Team Red"])
+        )
+    }
 }
@@ -0,0 +1,168 @@
+use crate::business::affectations::{Affectation, Assignee};
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::locale::Locale;
+use crate::business::weekday_filter::WeekDayFilter;
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum DayKind {
+    OutsideRange,
+    FilteredOut,
+    Excluded,
+    Unassigned,
+    Assigned(Assignee),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CalendarDay {
+    pub date: NaiveDate,
+    pub in_month: bool,
+    pub kind: DayKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CalendarMonth {
+    pub year: i32,
+    pub month: u32,
+    pub weeks: Vec<Vec<CalendarDay>>,
+}
+
+pub fn calendar_months(
+    range: DateRange,
+    affectations: &[Affectation],
+    weekday_filter: &WeekDayFilter,
+    excluded_periods: &ExcludedPeriodsFilter,
+    locale: Locale,
+) -> Vec<CalendarMonth> {
+    let kind = |date: NaiveDate| {
+        if !range.contains(&date) {
+            DayKind::OutsideRange
+        } else if !weekday_filter.accepted_days().contains(&date.weekday()) {
+            DayKind::FilteredOut
+        } else if excluded_periods.excludes(&date) {
+            DayKind::Excluded
+        } else {
+            affectations
+                .iter()
+                .find(|affectation| affectation.date == date)
+                .map_or(DayKind::Unassigned, |affectation| {
+                    DayKind::Assigned(affectation.assignee.clone())
+                })
+        }
+    };
+    let mut months = vec![];
+    let mut first = range
+        .starting_date()
+        .with_day(1)
+        .expect("Every month has a first day");
+    while first <= range.ending_date() {
+        let next = first + Months::new(1);
+        let start = locale.week_start(first);
+        let last = next.pred_opt().expect("Dates have a previous day");
+        let end = locale.week_start(last) + Days::new(6);
+        let days: Vec<CalendarDay> = start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .map(|date| CalendarDay {
+                date,
+                in_month: date.month() == first.month(),
+                kind: kind(date),
+            })
+            .collect();
+        months.push(CalendarMonth {
+            year: first.year(),
+            month: first.month(),
+            weeks: days.chunks(7).map(<[CalendarDay]>::to_vec).collect(),
+        });
+        first = next;
+    }
+    months
+}
+
+#[cfg(test)]
+mod calendar_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::person;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn months(range: (&str, &str), affectations: &[Affectation]) -> Vec<CalendarMonth> {
+        months_in(range, affectations, Locale::French)
+    }
+
+    fn months_in(
+        range: (&str, &str),
+        affectations: &[Affectation],
+        locale: Locale,
+    ) -> Vec<CalendarMonth> {
+        calendar_months(
+            range.try_into().unwrap(),
+            affectations,
+            &WeekDayFilter::default(),
+            &ExcludedPeriodsFilter::from(vec![
+                DateRange::try_from(("2025-02-10", "2025-02-11")).unwrap()
+            ]),
+            locale,
+        )
+    }
+
+    fn day<'a>(months: &'a [CalendarMonth], date: &str) -> &'a CalendarDay {
+        months
+            .iter()
+            .flat_map(|month| month.weeks.iter().flatten())
+            .find(|day| day.in_month && day.date == date_from(date))
+            .unwrap()
+    }
+
+    #[rstest]
+    fn have_a_page_per_month_of_the_range() {
+        let months = months(("2025-01-20", "2025-03-05"), &[]);
+        assert_that!(
+            months
+                .iter()
+                .map(|month| (month.year, month.month))
+                .collect::<Vec<_>>(),
+            container_eq([(2025, 1), (2025, 2), (2025, 3)])
+        )
+    }
+
+    #[rstest]
+    fn lay_out_full_weeks_from_monday_to_sunday() {
+        let months = months(("2025-02-01", "2025-02-28"), &[]);
+        let weeks = &months[0].weeks;
+        assert_that!(weeks.len(), eq(5));
+        assert_that!(weeks.iter().all(|week| week.len() == 7), is_true());
+        assert_that!(weeks[0][0].date, eq(date_from("2025-01-27")));
+        assert_that!(weeks[0][0].in_month, is_false());
+        assert_that!(weeks[4][6].date, eq(date_from("2025-03-02")));
+    }
+
+    #[rstest]
+    fn start_weeks_on_sunday_in_english() {
+        let months = months_in(("2025-02-01", "2025-02-28"), &[], Locale::English);
+        let weeks = &months[0].weeks;
+        assert_that!(weeks.len(), eq(5));
+        assert_that!(weeks[0][0].date, eq(date_from("2025-01-26")));
+        assert_that!(weeks[4][6].date, eq(date_from("2025-03-01")));
+    }
+
+    #[rstest]
+    #[case::before_the_range("2025-02-02", DayKind::OutsideRange)]
+    #[case::weekday_filtered_out("2025-02-05", DayKind::FilteredOut)]
+    #[case::excluded("2025-02-10", DayKind::Excluded)]
+    #[case::nobody_available("2025-02-13", DayKind::Unassigned)]
+    #[case::assigned("2025-02-04", DayKind::Assigned(Assignee::Person(person(1, "Xavier"))))]
+    fn tell_what_happens_each_day(#[case] date: &str, #[case] expected: DayKind) {
+        let months = months(
+            ("2025-02-03", "2025-02-28"),
+            &[Affectation {
+                date: date_from("2025-02-04"),
+                assignee: Assignee::Person(person(1, "Xavier")),
+            }],
+        );
+        assert_that!(day(&months, date).kind, eq(&expected))
+    }
+}
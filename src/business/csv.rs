@@ -0,0 +1,457 @@
+use crate::business::affectations::{Affectation, Assignee};
+use crate::business::group::Group;
+use crate::business::locale::Locale;
+use crate::business::name::{Name, NameError};
+use crate::business::overrides::Overrides;
+use crate::business::person::People;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, NaiveDate};
+
+const HEADER: [&str; 4] = ["date", "weekday", "name", "role"];
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub date_format: String,
+    pub locale: Locale,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            date_format: "%Y-%m-%d".to_string(),
+            locale: Locale::default(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct CsvImport {
+    pub overrides: Overrides,
+    pub errors: Vec<CsvImportError>,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum CsvExportError {
+    #[error("Invalid date format {0}")]
+    InvalidDateFormat(String),
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum CsvImportError {
+    #[error("Line {line}: expected date, weekday, name and role but found {count} columns")]
+    WrongColumnCount { line: usize, count: usize },
+    #[error("Line {line}: unterminated quoted field")]
+    UnterminatedQuote { line: usize },
+    #[error("Line {line}: invalid date {value}")]
+    InvalidDate { line: usize, value: String },
+    #[error("Line {line}: {error}")]
+    InvalidName { line: usize, error: NameError },
+    #[error("Line {line}: nobody is called {name}")]
+    UnknownName { line: usize, name: Name },
+}
+
+pub fn export_csv(
+    affectations: &[Affectation],
+    options: &CsvOptions,
+) -> Result<String, CsvExportError> {
+    let date_format: Vec<Item> = StrftimeItems::new(&options.date_format).collect();
+    if date_format.contains(&Item::Error) {
+        return Err(CsvExportError::InvalidDateFormat(
+            options.date_format.clone(),
+        ));
+    }
+    let mut rows = vec![HEADER.map(str::to_string).to_vec()];
+    for affectation in affectations {
+        let role = match &affectation.assignee {
+            Assignee::Person(_) => String::new(),
+            Assignee::Group { name, .. } => name.to_string(),
+        };
+        let date = affectation
+            .date
+            .format_with_items(date_format.iter())
+            .to_string();
+        let weekday = options
+            .locale
+            .weekday_name(affectation.date.weekday())
+            .to_string();
+        let people = affectation.assignee.people();
+        let names: Vec<String> = if people.is_empty() {
+            vec![String::new()]
+        } else {
+            people.iter().map(|person| person.to_string()).collect()
+        };
+        for name in names {
+            rows.push(vec![date.clone(), weekday.clone(), name, role.clone()]);
+        }
+    }
+    Ok(rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|field| quote(field, options.delimiter))
+                .collect::<Vec<_>>()
+                .join(&options.delimiter.to_string())
+                + "\r\n"
+        })
+        .collect())
+}
+
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn import_csv(
+    content: &str,
+    options: &CsvOptions,
+    people: &People,
+    groups: &[Group],
+) -> CsvImport {
+    let mut import = CsvImport::default();
+    for (line, fields) in records(content, options.delimiter) {
+        let fields = match fields {
+            Ok(fields) => fields,
+            Err(error) => {
+                import.errors.push(error);
+                continue;
+            }
+        };
+        if line == 1
+            && fields
+                .first()
+                .is_some_and(|field| field.eq_ignore_ascii_case(HEADER[0]))
+        {
+            continue;
+        }
+        match pinned(line, &fields, options, people, groups) {
+            Ok((date, assignee)) => import.overrides = import.overrides.pin(date, assignee),
+            Err(error) => import.errors.push(error),
+        }
+    }
+    import
+}
+
+fn pinned(
+    line: usize,
+    fields: &[String],
+    options: &CsvOptions,
+    people: &People,
+    groups: &[Group],
+) -> Result<(NaiveDate, Assignee), CsvImportError> {
+    let [date, _weekday, name, role @ ..] = fields else {
+        return Err(CsvImportError::WrongColumnCount {
+            line,
+            count: fields.len(),
+        });
+    };
+    if role.len() > 1 {
+        return Err(CsvImportError::WrongColumnCount {
+            line,
+            count: fields.len(),
+        });
+    }
+    let date = NaiveDate::parse_from_str(date.trim(), &options.date_format)
+        .or_else(|_| options.locale.parse_date(date))
+        .map_err(|_| CsvImportError::InvalidDate {
+            line,
+            value: date.clone(),
+        })?;
+    let name = Name::try_from(name.clone())
+        .map_err(|error| CsvImportError::InvalidName { line, error })?;
+    let group = role
+        .first()
+        .filter(|role| !role.trim().is_empty())
+        .and_then(|role| Name::try_from(role.clone()).ok())
+        .and_then(|role| {
+            groups
+                .iter()
+                .find(|group| group.name().is_similar_to(&role))
+        })
+        .and_then(|group| group.resolve(people));
+    if let Some(group) = group {
+        return Ok((date, group));
+    }
+    people
+        .iter()
+        .find(|person| person.name().is_similar_to(&name))
+        .map(|person| (date, Assignee::Person(person.clone())))
+        .ok_or(CsvImportError::UnknownName { line, name })
+}
+
+fn records(content: &str, delimiter: char) -> Vec<(usize, Result<Vec<String>, CsvImportError>)> {
+    let mut records = vec![];
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let first_line = line;
+        let mut fields = vec![];
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut terminated = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' if quoted => quoted = false,
+                '"' if field.is_empty() => quoted = true,
+                '\n' => {
+                    line += 1;
+                    if quoted {
+                        field.push(c);
+                    } else {
+                        terminated = true;
+                        break;
+                    }
+                }
+                '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+                c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+                c => field.push(c),
+            }
+        }
+        fields.push(field);
+        if quoted {
+            records.push((
+                first_line,
+                Err(CsvImportError::UnterminatedQuote { line: first_line }),
+            ));
+        } else if fields.iter().any(|field| !field.trim().is_empty()) {
+            records.push((first_line, Ok(fields)));
+        }
+        if !terminated {
+            break;
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod csv_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::overrides::Pin;
+    use crate::business::person::test_helpers::{people, person};
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn affectation(date: &str, id: u64, name: &str) -> Affectation {
+        Affectation {
+            date: date_from(date),
+            assignee: Assignee::Person(person(id, name)),
+        }
+    }
+
+    #[rstest]
+    fn export_a_row_per_affectation() {
+        let csv = export_csv(
+            &[
+                affectation("2025-01-10", 1, "Xavier"),
+                affectation("2025-01-13", 2, "Merve"),
+            ],
+            &CsvOptions::default(),
+        )
+        .unwrap();
+        assert_that!(
+            csv,
+            eq("date,weekday,name,role\r\n\
+                2025-01-10,Vendredi,Xavier,\r\n\
+                2025-01-13,Lundi,Merve,\r\n")
+        )
+    }
+
+    #[rstest]
+    fn export_a_row_per_group_member_with_the_group_as_role() {
+        let team = Assignee::Group {
+            name: "Team Blue".into(),
+            members: vec![person(1, "Xavier"), person(2, "Merve")],
+        };
+        let csv = export_csv(
+            &[Affectation {
+                date: date_from("2025-01-10"),
+                assignee: team,
+            }],
+            &CsvOptions::default(),
+        )
+        .unwrap();
+        assert_that!(
+            csv,
+            eq("date,weekday,name,role\r\n\
+                2025-01-10,Vendredi,Xavier,Team Blue\r\n\
+                2025-01-10,Vendredi,Merve,Team Blue\r\n")
+        )
+    }
+
+    #[rstest]
+    fn keep_the_date_of_a_group_without_members() {
+        let team = Assignee::Group {
+            name: "Team Blue".into(),
+            members: vec![],
+        };
+        let csv = export_csv(
+            &[Affectation {
+                date: date_from("2025-01-10"),
+                assignee: team,
+            }],
+            &CsvOptions::default(),
+        )
+        .unwrap();
+        assert_that!(
+            csv,
+            eq("date,weekday,name,role\r\n\
+                2025-01-10,Vendredi,,Team Blue\r\n")
+        )
+    }
+
+    #[rstest]
+    fn reject_an_invalid_date_format() {
+        let options = CsvOptions {
+            date_format: "%Y-%Q".to_string(),
+            ..CsvOptions::default()
+        };
+        assert_that!(
+            export_csv(&[affectation("2025-01-10", 1, "Xavier")], &options),
+            err(eq(&CsvExportError::InvalidDateFormat("%Y-%Q".to_string())))
+        )
+    }
+
+    #[rstest]
+    fn use_the_configured_delimiter_and_date_format() {
+        let options = CsvOptions {
+            delimiter: ';',
+            date_format: "%d/%m/%Y".to_string(),
+            locale: Locale::English,
+        };
+        let csv = export_csv(&[affectation("2025-01-10", 1, "Dupont; Jean")], &options).unwrap();
+        assert_that!(
+            csv,
+            eq("date;weekday;name;role\r\n\
+                10/01/2025;Friday;\"Dupont; Jean\";\r\n")
+        )
+    }
+
+    #[rstest]
+    fn import_an_exported_schedule_as_pinned_dates() {
+        let people = people(&["Xavier", "Merve"]);
+        let csv = export_csv(
+            &[
+                affectation("2025-01-10", 1, "Xavier"),
+                affectation("2025-01-13", 2, "Merve"),
+            ],
+            &CsvOptions::default(),
+        )
+        .unwrap();
+        let import = import_csv(&csv, &CsvOptions::default(), &people, &[]);
+        assert_that!(
+            import,
+            eq(&CsvImport {
+                overrides: Overrides::default()
+                    .pin(
+                        date_from("2025-01-10"),
+                        Assignee::Person(person(1, "Xavier"))
+                    )
+                    .pin(
+                        date_from("2025-01-13"),
+                        Assignee::Person(person(2, "Merve"))
+                    ),
+                errors: vec![]
+            })
+        )
+    }
+
+    #[rstest]
+    fn import_rows_of_a_group() {
+        let people = people(&["Xavier", "Merve"]);
+        let groups = [Group::new("Team Blue".into())
+            .toggle_member(1.into())
+            .toggle_member(2.into())];
+        let import = import_csv(
+            "2025-01-10,Vendredi,Xavier,team blue\n2025-01-10,Vendredi,Merve,team blue\n",
+            &CsvOptions::default(),
+            &people,
+            &groups,
+        );
+        assert_that!(
+            import.overrides.get(&date_from("2025-01-10")),
+            some(eq(&Pin::from(groups[0].resolve(&people).unwrap())))
+        )
+    }
+
+    #[rstest]
+    fn import_quoted_fields_spanning_lines() {
+        let people = people(&["Jean Dupont"]);
+        let import = import_csv(
+            "\"2025-01-10\",\"Ven\ndredi\",\"Jean \"\"Dupont\"\"\",\n",
+            &CsvOptions::default(),
+            &people,
+            &[],
+        );
+        assert_that!(
+            import.errors,
+            container_eq([CsvImportError::UnknownName {
+                line: 1,
+                name: "Jean \"Dupont\"".into()
+            }])
+        )
+    }
+
+    #[rstest]
+    fn report_errors_with_their_line_number() {
+        let people = people(&["Xavier"]);
+        let import = import_csv(
+            "date,weekday,name,role\n\
+             2025-01-10,Vendredi,Xavier,\n\
+             2025-02-30,Dimanche,Xavier,\n\
+             2025-01-13,Lundi\n\
+             2025-01-14,Mardi,Merve,\n\
+             2025-01-15,Mercredi, ,\n\
+             \n\
+             2025-01-16,Jeudi,\"Xavier,\n",
+            &CsvOptions::default(),
+            &people,
+            &[],
+        );
+        assert_that!(import.overrides.len(), eq(1));
+        assert_that!(
+            import.errors,
+            container_eq([
+                CsvImportError::InvalidDate {
+                    line: 3,
+                    value: "2025-02-30".to_string()
+                },
+                CsvImportError::WrongColumnCount { line: 4, count: 2 },
+                CsvImportError::UnknownName {
+                    line: 5,
+                    name: "Merve".into()
+                },
+                CsvImportError::InvalidName {
+                    line: 6,
+                    error: NameError::EmptyName
+                },
+                CsvImportError::UnterminatedQuote { line: 8 },
+            ])
+        )
+    }
+
+    #[rstest]
+    fn accept_dates_in_the_locale_format() {
+        let people = people(&["Xavier"]);
+        let import = import_csv(
+            "10/01/2025,Vendredi,Xavier,\n",
+            &CsvOptions::default(),
+            &people,
+            &[],
+        );
+        assert_that!(import.errors, is_empty());
+        assert_that!(
+            import.overrides.get(&date_from("2025-01-10")),
+            some(eq(&Pin::Person(1.into())))
+        )
+    }
+}
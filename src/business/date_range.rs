@@ -1,26 +1,81 @@
+use crate::business::locale::{DateParseError, Locale};
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "SerializedDateRange", into = "SerializedDateRange")]
 pub struct DateRange {
     starting_date: NaiveDate,
     ending_date: NaiveDate,
 }
 
+#[derive(Serialize, Deserialize)]
+struct SerializedDateRange {
+    start: String,
+    end: String,
+}
+
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
 pub enum DateRangeError {
     #[error("Wrong format for starting date {0}")]
     StartDateFormatWrong(String),
     #[error("Wrong format for ending date {0}")]
     EndDateFormatWrong(String),
+    #[error("Invalid starting date {0}: {1}")]
+    StartDateInvalid(String, DateParseError),
+    #[error("Invalid ending date {0}: {1}")]
+    EndDateInvalid(String, DateParseError),
     #[error("Starting date {0} is after ending date {1}")]
     StartDateAfterEndDate(NaiveDate, NaiveDate),
 }
 
 impl DateRange {
+    pub fn new(starting_date: NaiveDate, ending_date: NaiveDate) -> Result<Self, DateRangeError> {
+        if starting_date > ending_date {
+            Err(DateRangeError::StartDateAfterEndDate(
+                starting_date,
+                ending_date,
+            ))
+        } else {
+            Ok(DateRange {
+                starting_date,
+                ending_date,
+            })
+        }
+    }
+
+    pub fn parse(
+        starting_date: &str,
+        ending_date: &str,
+        locale: Locale,
+    ) -> Result<Self, DateRangeError> {
+        let starting_date = locale
+            .parse_date(starting_date)
+            .map_err(|error| match error {
+                DateParseError::UnrecognizedFormat => {
+                    DateRangeError::StartDateFormatWrong(starting_date.to_string())
+                }
+                error => DateRangeError::StartDateInvalid(starting_date.to_string(), error),
+            })?;
+        let ending_date = locale
+            .parse_date(ending_date)
+            .map_err(|error| match error {
+                DateParseError::UnrecognizedFormat => {
+                    DateRangeError::EndDateFormatWrong(ending_date.to_string())
+                }
+                error => DateRangeError::EndDateInvalid(ending_date.to_string(), error),
+            })?;
+        DateRange::new(starting_date, ending_date)
+    }
+
     pub fn contains(&self, date: &NaiveDate) -> bool {
         self.starting_date <= *date && self.ending_date >= *date
     }
 
+    pub fn overlaps(&self, other: &DateRange) -> bool {
+        self.starting_date <= other.ending_date && other.starting_date <= self.ending_date
+    }
+
     pub fn starting_date(&self) -> NaiveDate {
         self.starting_date
     }
@@ -45,20 +100,23 @@ impl TryFrom<(&str, &str)> for DateRange {
     type Error = DateRangeError;
 
     fn try_from((starting_date, ending_date): (&str, &str)) -> Result<Self, Self::Error> {
-        let starting_date = NaiveDate::parse_from_str(starting_date, "%Y-%m-%d")
-            .map_err(|_| DateRangeError::StartDateFormatWrong(starting_date.to_string()))?;
-        let ending_date = NaiveDate::parse_from_str(ending_date, "%Y-%m-%d")
-            .map_err(|_| DateRangeError::EndDateFormatWrong(ending_date.to_string()))?;
-        if starting_date > ending_date {
-            Err(DateRangeError::StartDateAfterEndDate(
-                starting_date,
-                ending_date,
-            ))
-        } else {
-            Ok(DateRange {
-                starting_date,
-                ending_date,
-            })
+        DateRange::parse(starting_date, ending_date, Locale::default())
+    }
+}
+
+impl TryFrom<SerializedDateRange> for DateRange {
+    type Error = DateRangeError;
+
+    fn try_from(value: SerializedDateRange) -> Result<Self, Self::Error> {
+        (value.start.as_str(), value.end.as_str()).try_into()
+    }
+}
+
+impl From<DateRange> for SerializedDateRange {
+    fn from(value: DateRange) -> Self {
+        SerializedDateRange {
+            start: value.starting_date.to_string(),
+            end: value.ending_date.to_string(),
         }
     }
 }
@@ -76,6 +134,7 @@ pub mod test_helpers {
 mod date_range_should {
     use crate::business::date_range::test_helpers::date_from;
     use crate::business::date_range::{DateRange, DateRangeError};
+    use crate::business::locale::{DateParseError, Locale};
     use chrono::NaiveDate;
     use core::str::FromStr;
     use googletest::assert_that;
@@ -97,9 +156,36 @@ mod date_range_should {
         );
     }
 
+    #[rstest]
+    #[case::french_format("01/01/2025", "2025-01-01")]
+    #[case::iso_week_date("2025-W01-3", "2025-01-01")]
+    #[case::french_textual_month("1er janvier 2025", "2025-01-01")]
+    fn build_from_dates_in_other_formats(#[case] starting_date: &str, #[case] expected: &str) {
+        let result: Result<DateRange, _> = (starting_date, "3000-12-30").try_into();
+        assert_that!(
+            result,
+            ok(eq(&DateRange {
+                starting_date: date_from(expected),
+                ending_date: date_from("3000-12-30")
+            }))
+        );
+    }
+
+    #[rstest]
+    fn parse_dates_according_to_the_locale() {
+        let result = DateRange::parse("01/02/2025", "01/03/2025", Locale::English);
+        assert_that!(
+            result,
+            ok(eq(&DateRange {
+                starting_date: date_from("2025-01-02"),
+                ending_date: date_from("2025-01-03")
+            }))
+        );
+    }
+
     #[rstest]
     #[case::wrong_separation("2025/01/01")]
-    #[case::swap_day_and_month("2025-13-01")]
+    #[case::not_a_date("demain")]
     fn fails_to_build_when_starting_date_is_badly_formatted(#[case] starting_date: &str) {
         let result: Result<DateRange, _> = (starting_date, "3000-12-30").try_into();
         assert_that!(
@@ -112,7 +198,7 @@ mod date_range_should {
 
     #[rstest]
     #[case::wrong_separation("2025/01/01")]
-    #[case::swap_day_and_month("2025-13-01")]
+    #[case::not_a_date("demain")]
     fn fails_to_build_when_ending_date_is_badly_formatted(#[case] ending_date: &str) {
         let result: Result<DateRange, _> = ("0001-01-01", ending_date).try_into();
         assert_that!(
@@ -121,6 +207,34 @@ mod date_range_should {
         );
     }
 
+    #[rstest]
+    fn fails_to_build_when_starting_date_does_not_exist() {
+        let result: Result<DateRange, _> = ("2025-13-01", "3000-12-30").try_into();
+        assert_that!(
+            result,
+            err(eq(&DateRangeError::StartDateInvalid(
+                "2025-13-01".to_string(),
+                DateParseError::NonExistentMonth(13)
+            )))
+        );
+    }
+
+    #[rstest]
+    fn fails_to_build_when_ending_date_does_not_exist() {
+        let result: Result<DateRange, _> = ("0001-01-01", "30/02/2025").try_into();
+        assert_that!(
+            result,
+            err(eq(&DateRangeError::EndDateInvalid(
+                "30/02/2025".to_string(),
+                DateParseError::NonExistentDay {
+                    year: 2025,
+                    month: 2,
+                    day: 30
+                }
+            )))
+        );
+    }
+
     #[rstest]
     #[case::big_difference("3000-12-30", "0001-01-01")]
     #[case::one_day_difference("2025-01-02", "2025-01-01")]
@@ -161,4 +275,22 @@ mod date_range_should {
         assert_that!(range.contains(&date_from("2025-01-02")), is_true());
         assert_that!(range.contains(&date_from("2025-01-04")), is_false());
     }
+
+    #[rstest]
+    #[case::same("2025-01-01", "2025-01-03", true)]
+    #[case::inside("2025-01-02", "2025-01-02", true)]
+    #[case::touching_end("2025-01-03", "2025-01-05", true)]
+    #[case::touching_start("2024-12-30", "2025-01-01", true)]
+    #[case::before("2024-12-30", "2024-12-31", false)]
+    #[case::after("2025-01-04", "2025-01-05", false)]
+    fn can_check_if_it_overlaps_another_range(
+        #[case] starting_date: &str,
+        #[case] ending_date: &str,
+        #[case] expected: bool,
+    ) {
+        let range: DateRange = ("2025-01-01", "2025-01-03").try_into().unwrap();
+        let other: DateRange = (starting_date, ending_date).try_into().unwrap();
+        assert_that!(range.overlaps(&other), eq(expected));
+        assert_that!(other.overlaps(&range), eq(expected))
+    }
 }
@@ -1,32 +1,77 @@
 use crate::business::date_range::DateRange;
 use chrono::NaiveDate;
-use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<LabelledPeriod>", into = "Vec<LabelledPeriod>")]
 pub struct ExcludedPeriodsFilter {
-    excluded_periods: BTreeSet<DateRange>,
+    excluded_periods: BTreeMap<DateRange, Option<String>>,
 }
 
-impl Default for ExcludedPeriodsFilter {
-    fn default() -> Self {
+#[derive(Serialize, Deserialize)]
+struct LabelledPeriod {
+    #[serde(flatten)]
+    period: DateRange,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+impl From<Vec<DateRange>> for ExcludedPeriodsFilter {
+    fn from(value: Vec<DateRange>) -> Self {
         ExcludedPeriodsFilter {
-            excluded_periods: BTreeSet::from([]),
+            excluded_periods: value.into_iter().map(|period| (period, None)).collect(),
         }
     }
 }
 
-impl From<Vec<DateRange>> for ExcludedPeriodsFilter {
-    fn from(value: Vec<DateRange>) -> Self {
+impl From<ExcludedPeriodsFilter> for Vec<DateRange> {
+    fn from(value: ExcludedPeriodsFilter) -> Self {
+        value.excluded_periods.into_keys().collect()
+    }
+}
+
+impl From<Vec<LabelledPeriod>> for ExcludedPeriodsFilter {
+    fn from(value: Vec<LabelledPeriod>) -> Self {
         ExcludedPeriodsFilter {
-            excluded_periods: BTreeSet::from_iter(value),
+            excluded_periods: value
+                .into_iter()
+                .map(|labelled| (labelled.period, labelled.label))
+                .collect(),
         }
     }
 }
 
+impl From<ExcludedPeriodsFilter> for Vec<LabelledPeriod> {
+    fn from(value: ExcludedPeriodsFilter) -> Self {
+        value
+            .excluded_periods
+            .into_iter()
+            .map(|(period, label)| LabelledPeriod { period, label })
+            .collect()
+    }
+}
+
 impl ExcludedPeriodsFilter {
+    pub fn periods(&self) -> impl Iterator<Item = &DateRange> {
+        self.excluded_periods.keys()
+    }
+
+    pub fn label(&self, period: &DateRange) -> Option<&str> {
+        self.excluded_periods
+            .get(period)
+            .and_then(|label| label.as_deref())
+    }
+
+    pub fn excludes(&self, date: &NaiveDate) -> bool {
+        self.excluded_periods
+            .keys()
+            .any(|excluded| excluded.contains(date))
+    }
+
     pub fn insert(self, period: DateRange) -> Self {
         let mut excluded_periods = self.excluded_periods;
-        excluded_periods.insert(period);
+        excluded_periods.entry(period).or_insert(None);
         Self { excluded_periods }
     }
 
@@ -35,6 +80,64 @@ impl ExcludedPeriodsFilter {
         excluded_periods.remove(period);
         Self { excluded_periods }
     }
+
+    pub fn with_label(self, period: &DateRange, label: Option<String>) -> Self {
+        let label = label
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty());
+        let mut excluded_periods = self.excluded_periods;
+        if let Some(current) = excluded_periods.get_mut(period) {
+            *current = label;
+        }
+        Self { excluded_periods }
+    }
+
+    pub fn replace(self, period: &DateRange, by: DateRange) -> Self {
+        let mut excluded_periods = self.excluded_periods;
+        if let Some(label) = excluded_periods.remove(period) {
+            let existing = excluded_periods.remove(&by).flatten();
+            excluded_periods.insert(by, joined_labels([label, existing]));
+        }
+        Self { excluded_periods }
+    }
+
+    pub fn overlapping(&self, period: &DateRange) -> Vec<DateRange> {
+        self.excluded_periods
+            .keys()
+            .filter(|other| *other != period && other.overlaps(period))
+            .copied()
+            .collect()
+    }
+
+    pub fn merge(self, period: &DateRange) -> Self {
+        let overlapping = self.overlapping(period);
+        if overlapping.is_empty() || !self.excluded_periods.contains_key(period) {
+            return self;
+        }
+        let mut excluded_periods = self.excluded_periods;
+        let mut merged = *period;
+        let mut labels = vec![];
+        for part in std::iter::once(*period).chain(overlapping) {
+            labels.push(excluded_periods.remove(&part).flatten());
+            merged = DateRange::new(
+                merged.starting_date().min(part.starting_date()),
+                merged.ending_date().max(part.ending_date()),
+            )
+            .expect("The union of two ranges is a range");
+        }
+        excluded_periods.insert(merged, joined_labels(labels));
+        Self { excluded_periods }.merge(&merged)
+    }
+}
+
+fn joined_labels(labels: impl IntoIterator<Item = Option<String>>) -> Option<String> {
+    let mut joined: Vec<String> = vec![];
+    for label in labels.into_iter().flatten() {
+        if !joined.contains(&label) {
+            joined.push(label);
+        }
+    }
+    (!joined.is_empty()).then(|| joined.join(", "))
 }
 
 #[derive(Clone, Debug)]
@@ -50,13 +153,7 @@ where
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iterator.find(|day| {
-            !self
-                .filter
-                .excluded_periods
-                .iter()
-                .any(|excluded| excluded.contains(day))
-        })
+        self.iterator.find(|day| !self.filter.excludes(day))
     }
 }
 
@@ -108,6 +205,119 @@ mod day_filter_should {
         assert_that!(filter.excluded_periods.len(), eq(0))
     }
 
+    #[rstest]
+    fn tell_if_a_date_is_excluded() {
+        let filter = ExcludedPeriodsFilter::default();
+        let filter = filter.insert(DateRange::try_from(("2025-01-09", "2025-01-11")).unwrap());
+        assert_that!(filter.excludes(&date_from("2025-01-10")), is_true());
+        assert_that!(filter.excludes(&date_from("2025-01-12")), is_false())
+    }
+
+    fn period(start: &str, end: &str) -> DateRange {
+        DateRange::try_from((start, end)).unwrap()
+    }
+
+    #[rstest]
+    fn label_a_period() {
+        let christmas = period("2024-12-21", "2025-01-05");
+        let filter = ExcludedPeriodsFilter::default()
+            .insert(christmas)
+            .with_label(&christmas, Some(" Vacances de Noël ".to_string()));
+        assert_that!(filter.label(&christmas), some(eq("Vacances de Noël")));
+        assert_that!(
+            filter
+                .with_label(&christmas, Some("".to_string()))
+                .label(&christmas),
+            none()
+        )
+    }
+
+    #[rstest]
+    fn keep_the_label_when_a_period_is_edited() {
+        let wrong = period("2024-12-21", "2025-01-15");
+        let fixed = period("2024-12-21", "2025-01-05");
+        let filter = ExcludedPeriodsFilter::default()
+            .insert(wrong)
+            .with_label(&wrong, Some("Noël".to_string()))
+            .replace(&wrong, fixed);
+        assert_that!(
+            filter.periods().copied().collect::<Vec<_>>(),
+            container_eq([fixed])
+        );
+        assert_that!(filter.label(&fixed), some(eq("Noël")))
+    }
+
+    #[rstest]
+    fn combine_labels_when_a_period_is_edited_onto_another() {
+        let christmas = period("2024-12-21", "2025-01-05");
+        let typo = period("2024-12-21", "2025-01-15");
+        let filter = ExcludedPeriodsFilter::default()
+            .insert(christmas)
+            .with_label(&christmas, Some("Noël".to_string()))
+            .insert(typo)
+            .with_label(&typo, Some("Ski".to_string()))
+            .replace(&typo, christmas);
+        assert_that!(
+            filter.periods().copied().collect::<Vec<_>>(),
+            container_eq([christmas])
+        );
+        assert_that!(filter.label(&christmas), some(eq("Ski, Noël")))
+    }
+
+    #[rstest]
+    #[case::overlapping(("2025-01-03", "2025-01-10"), true)]
+    #[case::inside(("2025-01-02", "2025-01-02"), true)]
+    #[case::adjacent(("2025-01-06", "2025-01-10"), false)]
+    fn detect_overlaps(#[case] other: (&str, &str), #[case] expected: bool) {
+        let existing = period("2025-01-01", "2025-01-05");
+        let other = period(other.0, other.1);
+        let filter = ExcludedPeriodsFilter::default()
+            .insert(existing)
+            .insert(other);
+        assert_that!(filter.overlapping(&other).contains(&existing), eq(expected))
+    }
+
+    #[rstest]
+    fn merge_overlapping_periods() {
+        let first = period("2025-01-01", "2025-01-05");
+        let second = period("2025-01-03", "2025-01-10");
+        let third = period("2024-12-28", "2025-01-01");
+        let fourth = period("2025-01-11", "2025-01-12");
+        let filter = ExcludedPeriodsFilter::default()
+            .insert(first)
+            .with_label(&first, Some("Noël".to_string()))
+            .insert(second)
+            .with_label(&second, Some("Ski".to_string()))
+            .insert(third)
+            .insert(fourth)
+            .merge(&second);
+        let merged = period("2024-12-28", "2025-01-10");
+        assert_that!(
+            filter.periods().copied().collect::<Vec<_>>(),
+            container_eq([merged, fourth])
+        );
+        assert_that!(filter.label(&merged), some(eq("Ski, Noël")))
+    }
+
+    #[rstest]
+    fn read_periods_with_and_without_labels() {
+        let filter: ExcludedPeriodsFilter = serde_json::from_str(
+            r#"[{"start": "2025-01-01", "end": "2025-01-05"}, {"start": "2025-02-01", "end": "2025-02-02", "label": "Ski"}]"#,
+        )
+        .unwrap();
+        assert_that!(filter.label(&period("2025-01-01", "2025-01-05")), none());
+        assert_that!(
+            filter.label(&period("2025-02-01", "2025-02-02")),
+            some(eq("Ski"))
+        );
+        assert_that!(
+            serde_json::to_string(&filter).unwrap(),
+            eq(
+                r#"[{"start":"2025-01-01","end":"2025-01-05"},{"start":"2025-02-01","end":"2025-02-02","label":"Ski"}]"#
+            )
+        )
+    }
+
     #[rstest]
     fn can_be_used_to_filter_an_iterator() {
         let filter = ExcludedPeriodsFilter::default();
@@ -0,0 +1,126 @@
+use crate::business::affectations::Assignee;
+use crate::business::name::Name;
+use crate::business::person::{People, PersonId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Group {
+    name: Name,
+    members: Vec<PersonId>,
+}
+
+impl Group {
+    pub fn new(name: Name) -> Self {
+        Group {
+            name,
+            members: vec![],
+        }
+    }
+
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    pub fn members(&self) -> &[PersonId] {
+        &self.members
+    }
+
+    pub fn with_name(self, name: Name) -> Self {
+        Group { name, ..self }
+    }
+
+    pub fn toggle_member(self, member: PersonId) -> Self {
+        let mut members = self.members;
+        if let Some(position) = members.iter().position(|id| *id == member) {
+            members.remove(position);
+        } else {
+            members.push(member);
+        }
+        Group { members, ..self }
+    }
+
+    pub fn without_member(self, member: PersonId) -> Self {
+        let mut members = self.members;
+        members.retain(|id| *id != member);
+        Group { members, ..self }
+    }
+
+    pub fn resolve(&self, people: &People) -> Option<Assignee> {
+        let members: Vec<_> = self
+            .members
+            .iter()
+            .filter_map(|id| people.get(*id))
+            .cloned()
+            .collect();
+        if members.is_empty() {
+            return None;
+        }
+        Some(Assignee::Group {
+            name: self.name.clone(),
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod group_should {
+    use super::*;
+    use crate::business::person::test_helpers::{people, person};
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn toggle_members_in_and_out() {
+        let group = Group::new("Team Blue".into())
+            .toggle_member(1.into())
+            .toggle_member(2.into())
+            .toggle_member(1.into());
+        assert_that!(group.members(), container_eq([PersonId::from(2)]))
+    }
+
+    #[rstest]
+    fn resolve_its_members_among_people() {
+        let group = Group::new("Team Blue".into())
+            .toggle_member(2.into())
+            .toggle_member(1.into())
+            .toggle_member(42.into());
+        assert_that!(
+            group.resolve(&people(&["Xavier", "Merve"])),
+            some(eq(&Assignee::Group {
+                name: "Team Blue".into(),
+                members: vec![person(2, "Merve"), person(1, "Xavier")]
+            }))
+        )
+    }
+
+    #[rstest]
+    #[case::without_members(Group::new("Team Blue".into()))]
+    #[case::with_deleted_members(Group::new("Team Blue".into()).toggle_member(42.into()))]
+    fn not_resolve_without_known_members(#[case] group: Group) {
+        assert_that!(group.resolve(&people(&["Xavier", "Merve"])), none())
+    }
+
+    #[rstest]
+    fn forget_a_deleted_member() {
+        let group = Group::new("Team Blue".into())
+            .toggle_member(1.into())
+            .toggle_member(2.into())
+            .without_member(1.into());
+        assert_that!(group.members(), container_eq([PersonId::from(2)]))
+    }
+
+    #[rstest]
+    fn be_displayed_with_its_members() {
+        let group = Group::new("Team Blue".into())
+            .toggle_member(1.into())
+            .toggle_member(2.into());
+        assert_that!(
+            group
+                .resolve(&people(&["Xavier", "Merve"]))
+                .unwrap()
+                .to_string(),
+            eq("Team Blue (Xavier, Merve)")
+        )
+    }
+}
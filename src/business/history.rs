@@ -0,0 +1,156 @@
+use std::collections::VecDeque;
+
+pub const DEFAULT_LIMIT: usize = 100;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    limit: usize,
+}
+
+impl<T: PartialEq> History<T> {
+    pub fn new(present: T) -> Self {
+        History {
+            past: VecDeque::new(),
+            present,
+            future: vec![],
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    pub fn with_limit(self, limit: usize) -> Self {
+        let mut past = self.past;
+        while past.len() > limit {
+            past.pop_front();
+        }
+        History {
+            past,
+            limit,
+            ..self
+        }
+    }
+
+    pub fn present(&self) -> &T {
+        &self.present
+    }
+
+    pub fn record(self, state: T) -> Self {
+        if state == self.present {
+            return self;
+        }
+        let mut past = self.past;
+        past.push_back(self.present);
+        if past.len() > self.limit {
+            past.pop_front();
+        }
+        History {
+            past,
+            present: state,
+            future: vec![],
+            limit: self.limit,
+        }
+    }
+
+    pub fn undo(self) -> Self {
+        let mut past = self.past;
+        let Some(previous) = past.pop_back() else {
+            return History { past, ..self };
+        };
+        let mut future = self.future;
+        future.push(self.present);
+        History {
+            past,
+            present: previous,
+            future,
+            limit: self.limit,
+        }
+    }
+
+    pub fn redo(self) -> Self {
+        let mut future = self.future;
+        let Some(next) = future.pop() else {
+            return History { future, ..self };
+        };
+        let mut past = self.past;
+        past.push_back(self.present);
+        History {
+            past,
+            present: next,
+            future,
+            limit: self.limit,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod history_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn edited() -> History<&'static str> {
+        History::new("a").record("b").record("c")
+    }
+
+    #[rstest]
+    fn go_back_to_previous_states() {
+        let history = edited().undo();
+        assert_that!(history.present(), eq(&"b"));
+        assert_that!(history.undo().present(), eq(&"a"));
+    }
+
+    #[rstest]
+    fn redo_what_was_undone() {
+        assert_that!(edited().undo().undo().redo().present(), eq(&"b"))
+    }
+
+    #[rstest]
+    fn forget_the_redo_branch_on_new_edits() {
+        let history = edited().undo().record("d");
+        assert_that!(history.can_redo(), is_false());
+        assert_that!(history.undo().present(), eq(&"b"));
+    }
+
+    #[rstest]
+    fn ignore_states_identical_to_the_present() {
+        assert_that!(History::new("a").record("a").can_undo(), is_false())
+    }
+
+    #[rstest]
+    #[case::nothing_to_undo(History::new("a").undo(), "a")]
+    #[case::nothing_to_redo(History::new("a").record("b").redo(), "b")]
+    fn stay_put_at_either_end(#[case] history: History<&str>, #[case] expected: &str) {
+        assert_that!(history.present(), eq(&expected))
+    }
+
+    #[rstest]
+    fn keep_a_bounded_number_of_states() {
+        let history = History::new(0)
+            .with_limit(2)
+            .record(1)
+            .record(2)
+            .record(3)
+            .undo()
+            .undo()
+            .undo();
+        assert_that!(history.present(), eq(&1));
+        assert_that!(history.can_undo(), is_false());
+    }
+
+    #[rstest]
+    fn drop_the_oldest_states_when_the_limit_shrinks() {
+        let history = edited().with_limit(1).undo().undo();
+        assert_that!(history.present(), eq(&"b"))
+    }
+}
@@ -0,0 +1,917 @@
+use crate::business::affectations::Affectation;
+use crate::business::date_range::DateRange;
+use crate::business::locale::Locale;
+use crate::business::person::PersonId;
+use crate::business::project::ScheduleId;
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, Utc, Weekday};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct IcsExportOptions {
+    pub summary_template: String,
+    pub person: Option<PersonId>,
+}
+
+impl Default for IcsExportOptions {
+    fn default() -> Self {
+        IcsExportOptions {
+            summary_template: "Tour de {name}".to_string(),
+            person: None,
+        }
+    }
+}
+
+pub fn export_ics(
+    affectations: &[Affectation],
+    schedule: ScheduleId,
+    options: &IcsExportOptions,
+    timestamp: DateTime<Utc>,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//taking-turns//taking-turns//FR".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for affectation in affectations.iter().filter(|affectation| {
+        options.person.is_none_or(|id| {
+            affectation
+                .assignee
+                .people()
+                .iter()
+                .any(|person| person.id() == id)
+        })
+    }) {
+        lines.extend(event_lines(affectation, options, schedule, timestamp));
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+fn expand(template: &str, name: &str, date: &str) -> String {
+    let mut expanded = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("{name}") {
+            expanded.push_str(name);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("{date}") {
+            expanded.push_str(date);
+            rest = after;
+        } else {
+            expanded.push('{');
+            rest = &rest[1..];
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+fn event_lines(
+    affectation: &Affectation,
+    options: &IcsExportOptions,
+    schedule: ScheduleId,
+    timestamp: DateTime<Utc>,
+) -> Vec<String> {
+    let date = affectation.date;
+    let summary = expand(
+        &options.summary_template,
+        &affectation.assignee.name().to_string(),
+        &Locale::default().format_date(date),
+    );
+    let uid = match options.person {
+        Some(id) => format!("{}-{schedule}-{id}@taking-turns", ics_date(date)),
+        None => format!("{}-{schedule}@taking-turns", ics_date(date)),
+    };
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{uid}"),
+        format!("DTSTAMP:{}", timestamp.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART;VALUE=DATE:{}", ics_date(date)),
+        format!(
+            "DTEND;VALUE=DATE:{}",
+            ics_date(date.checked_add_days(Days::new(1)).unwrap_or(date))
+        ),
+        format!("SUMMARY:{}", escape(&summary)),
+        "TRANSP:TRANSPARENT".to_string(),
+    ];
+    lines.extend(affectation.assignee.people().iter().filter_map(|person| {
+        person
+            .email()
+            .map(|email| format!("ATTENDEE;CN={}:mailto:{email}", quote(&person.to_string())))
+    }));
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+fn ics_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn quote(parameter: &str) -> String {
+    if parameter.contains([':', ';', ',']) {
+        format!("\"{}\"", parameter.replace('"', ""))
+    } else {
+        parameter.to_string()
+    }
+}
+
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_length = 0;
+    for c in line.chars() {
+        if line_length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+    folded
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ImportedPeriod {
+    pub summary: String,
+    pub period: DateRange,
+    pub partial_day: bool,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RejectedEvent {
+    pub summary: String,
+    pub error: IcsImportError,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct IcsImport {
+    pub periods: Vec<ImportedPeriod>,
+    pub rejected: Vec<RejectedEvent>,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum IcsImportError {
+    #[error("Event has no start date")]
+    MissingStart,
+    #[error("Invalid date {0}")]
+    InvalidDate(String),
+    #[error("Invalid duration {0}")]
+    InvalidDuration(String),
+    #[error("Event ends before it starts")]
+    EndBeforeStart,
+    #[error("Event ends after the last supported date")]
+    EndOutOfRange,
+    #[error("Unsupported recurrence rule {0}")]
+    UnsupportedRecurrence(String),
+}
+
+#[derive(Debug, Default)]
+struct Event {
+    uid: Option<String>,
+    recurrence_id: Option<String>,
+    summary: String,
+    start: Option<String>,
+    end: Option<(String, bool)>,
+    duration: Option<String>,
+    rule: Option<String>,
+    excluded_dates: Vec<String>,
+    cancelled: bool,
+}
+
+pub fn import_ics(content: &str, horizon: &DateRange) -> IcsImport {
+    let mut import = IcsImport::default();
+    let events = events(content);
+    let moved: Vec<(String, String)> = events
+        .iter()
+        .filter_map(|event| Some((event.uid.clone()?, event.recurrence_id.clone()?)))
+        .collect();
+    for mut event in events {
+        if event.recurrence_id.is_none() {
+            event.excluded_dates.extend(
+                moved
+                    .iter()
+                    .filter(|(uid, _)| event.uid.as_ref() == Some(uid))
+                    .map(|(_, date)| date.clone()),
+            );
+        }
+        if event.cancelled {
+            continue;
+        }
+        let partial_day = event
+            .start
+            .as_ref()
+            .is_some_and(|start| start.contains('T'));
+        match periods(&event, horizon) {
+            Ok(periods) => {
+                import
+                    .periods
+                    .extend(periods.into_iter().map(|period| ImportedPeriod {
+                        summary: event.summary.clone(),
+                        period,
+                        partial_day,
+                    }))
+            }
+            Err(error) => import.rejected.push(RejectedEvent {
+                summary: event.summary.clone(),
+                error,
+            }),
+        }
+    }
+    import
+}
+
+fn unfolded_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn property(line: &str) -> Option<(String, Vec<&str>, &str)> {
+    let mut quoted = false;
+    let separator = line.char_indices().find_map(|(i, c)| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        (c == ':' && !quoted).then_some(i)
+    })?;
+    let (head, value) = (&line[..separator], &line[separator + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.to_uppercase();
+    Some((name, parts.collect(), value))
+}
+
+fn events(content: &str) -> Vec<Event> {
+    let mut events = vec![];
+    let mut components: Vec<String> = vec![];
+    let mut current: Option<Event> = None;
+    for line in unfolded_lines(content) {
+        let Some((name, parameters, value)) = property(&line) else {
+            continue;
+        };
+        match name.as_str() {
+            "BEGIN" => {
+                components.push(value.to_uppercase());
+                if value.eq_ignore_ascii_case("VEVENT") {
+                    current = Some(Event::default());
+                }
+                continue;
+            }
+            "END" => {
+                if components.pop().as_deref() == Some("VEVENT") {
+                    events.extend(current.take());
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if components.last().map(String::as_str) != Some("VEVENT") {
+            continue;
+        }
+        let Some(event) = current.as_mut() else {
+            continue;
+        };
+        match name.as_str() {
+            "UID" => event.uid = Some(value.to_string()),
+            "RECURRENCE-ID" => event.recurrence_id = Some(value.to_string()),
+            "SUMMARY" => event.summary = unescape(value),
+            "DTSTART" => event.start = Some(value.to_string()),
+            "DTEND" => {
+                let all_day = parameters
+                    .iter()
+                    .any(|parameter| parameter.eq_ignore_ascii_case("VALUE=DATE"))
+                    || !value.contains('T');
+                event.end = Some((value.to_string(), all_day))
+            }
+            "DURATION" => event.duration = Some(value.to_string()),
+            "RRULE" => event.rule = Some(value.to_string()),
+            "EXDATE" => event
+                .excluded_dates
+                .extend(value.split(',').map(str::to_string)),
+            "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+    events
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+fn date_of(value: &str) -> Result<NaiveDate, IcsImportError> {
+    value
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or(IcsImportError::InvalidDate(value.to_string()))
+}
+
+fn duration_in_days(value: &str) -> Result<u64, IcsImportError> {
+    let invalid = || IcsImportError::InvalidDuration(value.to_string());
+    let period = value.strip_prefix("P").ok_or_else(invalid)?;
+    let period = period.split('T').next().unwrap_or_default();
+    if period.is_empty() {
+        return Ok(1);
+    }
+    let amount = |digits: &str| digits.parse::<u64>().map_err(|_| invalid());
+    if let Some(days) = period.strip_suffix('D') {
+        amount(days)
+    } else if let Some(weeks) = period.strip_suffix('W') {
+        amount(weeks)?.checked_mul(7).ok_or_else(invalid)
+    } else {
+        Err(invalid())
+    }
+}
+
+fn periods(event: &Event, horizon: &DateRange) -> Result<Vec<DateRange>, IcsImportError> {
+    let start = date_of(event.start.as_ref().ok_or(IcsImportError::MissingStart)?)?;
+    let days = match (&event.end, &event.duration) {
+        (Some((end, all_day)), _) => {
+            let end = date_of(end)?;
+            if end < start {
+                return Err(IcsImportError::EndBeforeStart);
+            }
+            let days = (end - start).num_days() as u64;
+            if *all_day {
+                days.max(1)
+            } else {
+                days + 1
+            }
+        }
+        (None, Some(duration)) => duration_in_days(duration)?.max(1),
+        (None, None) => 1,
+    };
+    let excluded = event
+        .excluded_dates
+        .iter()
+        .map(|date| date_of(date))
+        .collect::<Result<Vec<_>, _>>()?;
+    let starts = match &event.rule {
+        Some(rule) => occurrences(start, rule, horizon)?,
+        None => vec![start],
+    };
+    let periods = starts
+        .into_iter()
+        .filter(|start| !excluded.contains(start))
+        .map(|start| {
+            start
+                .checked_add_days(Days::new(days - 1))
+                .and_then(|end| DateRange::new(start, end).ok())
+                .ok_or(IcsImportError::EndOutOfRange)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(periods
+        .into_iter()
+        .filter(|period| period.overlaps(horizon))
+        .collect())
+}
+
+const MAXIMUM_OCCURRENCES: usize = 10_000;
+
+fn occurrences(
+    start: NaiveDate,
+    rule: &str,
+    horizon: &DateRange,
+) -> Result<Vec<NaiveDate>, IcsImportError> {
+    let unsupported = || IcsImportError::UnsupportedRecurrence(rule.to_string());
+    let mut frequency = None;
+    let mut interval: u32 = 1;
+    let mut count = None;
+    let mut until = horizon.ending_date();
+    let mut weekdays = vec![];
+    for part in rule.split(';').filter(|part| !part.is_empty()) {
+        let (key, value) = part.split_once('=').ok_or_else(unsupported)?;
+        match key.to_uppercase().as_str() {
+            "FREQ" => frequency = Some(value.to_uppercase()),
+            "INTERVAL" => interval = value.parse().map_err(|_| unsupported())?,
+            "COUNT" => count = Some(value.parse::<usize>().map_err(|_| unsupported())?),
+            "UNTIL" => until = until.min(date_of(value)?),
+            "BYDAY" => {
+                weekdays = value
+                    .split(',')
+                    .map(|day| weekday(day).ok_or_else(unsupported))
+                    .collect::<Result<_, _>>()?
+            }
+            "WKST" => {}
+            _ => return Err(unsupported()),
+        }
+    }
+    if interval == 0 || (!weekdays.is_empty() && frequency.as_deref() != Some("WEEKLY")) {
+        return Err(unsupported());
+    }
+    let after_days = move |date: NaiveDate, k: u64, unit: u64| {
+        k.checked_mul(unit * interval as u64)
+            .and_then(|days| date.checked_add_days(Days::new(days)))
+    };
+    let after_months = move |k: u64, unit: u32| {
+        u32::try_from(k)
+            .ok()
+            .and_then(|k| k.checked_mul(unit * interval))
+            .and_then(|months| start.checked_add_months(Months::new(months)))
+            .filter(|date| date.day() == start.day())
+    };
+    let candidates: Box<dyn Iterator<Item = Option<NaiveDate>>> = match frequency.as_deref() {
+        Some("DAILY") => Box::new((0..).map(move |k| after_days(start, k, 1))),
+        Some("WEEKLY") if weekdays.is_empty() => {
+            Box::new((0..).map(move |k| after_days(start, k, 7)))
+        }
+        Some("WEEKLY") => {
+            let monday = start.week(Weekday::Mon).first_day();
+            Box::new((0..).flat_map(move |k| {
+                let week = after_days(monday, k, 7);
+                let mut days: Vec<_> = weekdays
+                    .iter()
+                    .map(|day| {
+                        week.and_then(|week| {
+                            week.checked_add_days(Days::new(day.num_days_from_monday() as u64))
+                        })
+                    })
+                    .filter(|day| day.is_none_or(|day| day >= start))
+                    .collect();
+                days.sort();
+                days
+            }))
+        }
+        Some("MONTHLY") => Box::new((0..).map(move |k| after_months(k, 1))),
+        Some("YEARLY") => {
+            interval.checked_mul(12).ok_or_else(unsupported)?;
+            Box::new((0..).map(move |k| after_months(k, 12)))
+        }
+        _ => return Err(unsupported()),
+    };
+    Ok(candidates
+        .take(MAXIMUM_OCCURRENCES)
+        .take_while(|date| date.is_none_or(|date| date <= until))
+        .flatten()
+        .take(count.unwrap_or(MAXIMUM_OCCURRENCES))
+        .collect())
+}
+
+fn weekday(value: &str) -> Option<Weekday> {
+    match value.to_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod ics_export_should {
+    use super::*;
+    use crate::business::affectations::Assignee;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::person;
+    use crate::business::person::Email;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn schedule() -> ScheduleId {
+        42.into()
+    }
+
+    fn timestamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T08:30:00Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    fn affectation(date: &str, id: u64, name: &str) -> Affectation {
+        Affectation {
+            date: date_from(date),
+            assignee: Assignee::Person(person(id, name)),
+        }
+    }
+
+    #[rstest]
+    fn produce_an_all_day_event_per_affectation() {
+        let ics = export_ics(
+            &[affectation("2025-01-10", 1, "Xavier")],
+            schedule(),
+            &IcsExportOptions::default(),
+            timestamp(),
+        );
+        assert_that!(
+            ics,
+            eq(&String::from(
+                "BEGIN:VCALENDAR\r\n\
+                VERSION:2.0\r\n\
+                PRODID:-//taking-turns//taking-turns//FR\r\n\
+                CALSCALE:GREGORIAN\r\n\
+                BEGIN:VEVENT\r\n\
+                UID:20250110-000000000000002a@taking-turns\r\n\
+                DTSTAMP:20250101T083000Z\r\n\
+                DTSTART;VALUE=DATE:20250110\r\n\
+                DTEND;VALUE=DATE:20250111\r\n\
+                SUMMARY:Tour de Xavier\r\n\
+                TRANSP:TRANSPARENT\r\n\
+                END:VEVENT\r\n\
+                END:VCALENDAR\r\n"
+            ))
+        )
+    }
+
+    #[rstest]
+    fn keep_the_same_uid_when_a_date_is_reassigned() {
+        let options = IcsExportOptions::default();
+        let uids = |affectations: &[Affectation]| {
+            export_ics(affectations, schedule(), &options, timestamp())
+                .lines()
+                .filter(|line| line.starts_with("UID:"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let before = uids(&[
+            affectation("2025-01-10", 1, "Xavier"),
+            affectation("2025-01-13", 2, "Merve"),
+        ]);
+        let after = uids(&[
+            affectation("2025-01-10", 2, "Merve"),
+            affectation("2025-01-13", 1, "Xavier"),
+        ]);
+        assert_that!(before, container_eq(after))
+    }
+
+    #[rstest]
+    fn keep_the_same_uids_when_people_and_days_are_added() {
+        let options = IcsExportOptions::default();
+        let uids = |affectations: &[Affectation]| {
+            export_ics(affectations, schedule(), &options, timestamp())
+                .lines()
+                .filter(|line| line.starts_with("UID:"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let before = uids(&[
+            affectation("2025-01-10", 1, "Xavier"),
+            affectation("2025-01-13", 2, "Merve"),
+        ]);
+        let after = uids(&[
+            affectation("2025-01-10", 1, "Xavier"),
+            affectation("2025-01-13", 2, "Merve"),
+            affectation("2025-01-14", 3, "Ana"),
+        ]);
+        assert_that!(after[..2].to_vec(), container_eq(before))
+    }
+
+    #[rstest]
+    fn tell_the_uids_of_distinct_schedules_apart() {
+        let options = IcsExportOptions::default();
+        let affectations = [affectation("2025-01-10", 1, "Xavier")];
+        let first = export_ics(&affectations, schedule(), &options, timestamp());
+        let second = export_ics(&affectations, 7.into(), &options, timestamp());
+        let uid = |ics: &str| {
+            ics.lines()
+                .find(|line| line.starts_with("UID:"))
+                .map(str::to_string)
+        };
+        assert_that!(uid(&first), not(eq(&uid(&second))))
+    }
+
+    #[rstest]
+    fn fill_the_summary_template() {
+        let options = IcsExportOptions {
+            summary_template: "Vaisselle, {name} le {date}".to_string(),
+            person: None,
+        };
+        let ics = export_ics(
+            &[affectation("2025-01-10", 1, "Xavier")],
+            schedule(),
+            &options,
+            timestamp(),
+        );
+        assert_that!(
+            ics,
+            contains_substring("SUMMARY:Vaisselle\\, Xavier le 10/01/2025\r\n")
+        )
+    }
+
+    #[rstest]
+    fn not_expand_placeholders_inside_names() {
+        let options = IcsExportOptions {
+            summary_template: "{name} le {date}".to_string(),
+            person: None,
+        };
+        let ics = export_ics(
+            &[affectation("2025-01-10", 1, "Xavier {date}")],
+            schedule(),
+            &options,
+            timestamp(),
+        );
+        assert_that!(
+            ics,
+            contains_substring("SUMMARY:Xavier {date} le 10/01/2025\r\n")
+        )
+    }
+
+    #[rstest]
+    fn export_only_the_turns_of_a_person() {
+        let options = IcsExportOptions {
+            person: Some(2.into()),
+            ..IcsExportOptions::default()
+        };
+        let affectations = [
+            affectation("2025-01-10", 1, "Xavier"),
+            affectation("2025-01-13", 2, "Merve"),
+        ];
+        let ics = export_ics(&affectations, schedule(), &options, timestamp());
+        assert_that!(ics, not(contains_substring("Xavier")));
+        assert_that!(
+            ics,
+            contains_substring("UID:20250113-000000000000002a-2@taking-turns")
+        )
+    }
+
+    #[rstest]
+    fn invite_people_with_an_email() {
+        let xavier = person(1, "Xavier").with_email(Some(
+            Email::try_from(String::from("xavier@example.com")).unwrap(),
+        ));
+        let ics = export_ics(
+            &[Affectation {
+                date: date_from("2025-01-10"),
+                assignee: Assignee::Person(xavier),
+            }],
+            schedule(),
+            &IcsExportOptions::default(),
+            timestamp(),
+        );
+        assert_that!(
+            ics,
+            contains_substring("ATTENDEE;CN=Xavier:mailto:xavier@example.com\r\n")
+        )
+    }
+
+    #[rstest]
+    fn fold_long_lines() {
+        let options = IcsExportOptions {
+            summary_template: "é".repeat(50),
+            person: None,
+        };
+        let ics = export_ics(
+            &[affectation("2025-01-10", 1, "Xavier")],
+            schedule(),
+            &options,
+            timestamp(),
+        );
+        assert_that!(ics.split("\r\n").map(str::len).max(), some(le(75)));
+        assert_that!(
+            ics.replace("\r\n ", ""),
+            contains_substring(format!("SUMMARY:{}\r\n", "é".repeat(50)))
+        )
+    }
+}
+
+#[cfg(test)]
+mod ics_import_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn calendar(events: &[&str]) -> String {
+        let events: Vec<String> = events
+            .iter()
+            .map(|event| format!("BEGIN:VEVENT\r\n{event}END:VEVENT\r\n"))
+            .collect();
+        format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{}END:VCALENDAR\r\n",
+            events.concat()
+        )
+    }
+
+    fn range(starting_date: &str, ending_date: &str) -> DateRange {
+        (starting_date, ending_date).try_into().unwrap()
+    }
+
+    fn horizon() -> DateRange {
+        range("2025-01-01", "2025-12-31")
+    }
+
+    fn imported_periods(event: &str) -> Vec<DateRange> {
+        import_ics(&calendar(&[event]), &horizon())
+            .periods
+            .into_iter()
+            .map(|imported| imported.period)
+            .collect()
+    }
+
+    #[rstest]
+    fn import_a_single_day_event() {
+        let import = import_ics(
+            &calendar(&["SUMMARY:Dentiste\r\nDTSTART;VALUE=DATE:20250110\r\n"]),
+            &horizon(),
+        );
+        assert_that!(
+            import.periods,
+            container_eq([ImportedPeriod {
+                summary: "Dentiste".to_string(),
+                period: range("2025-01-10", "2025-01-10"),
+                partial_day: false
+            }])
+        );
+        assert_that!(import.rejected, is_empty())
+    }
+
+    #[rstest]
+    fn flag_timed_events_as_partial_days() {
+        let import = import_ics(
+            &calendar(&["DTSTART:20250110T090000Z\r\nDTEND:20250110T100000Z\r\n"]),
+            &horizon(),
+        );
+        assert_that!(
+            import.periods,
+            container_eq([ImportedPeriod {
+                summary: String::new(),
+                period: range("2025-01-10", "2025-01-10"),
+                partial_day: true
+            }])
+        )
+    }
+
+    #[rstest]
+    fn ignore_the_properties_of_alarms() {
+        let import = import_ics(
+            &calendar(&["SUMMARY:Dentiste\r\nDTSTART;VALUE=DATE:20250110\r\n\
+                 BEGIN:VALARM\r\nACTION:DISPLAY\r\nSUMMARY:Alarm notification\r\n\
+                 DURATION:PT15M\r\nTRIGGER:-PT30M\r\nEND:VALARM\r\n"]),
+            &horizon(),
+        );
+        assert_that!(
+            import
+                .periods
+                .iter()
+                .map(|imported| (imported.summary.as_str(), imported.period))
+                .collect::<Vec<_>>(),
+            container_eq([("Dentiste", range("2025-01-10", "2025-01-10"))])
+        )
+    }
+
+    #[rstest]
+    fn import_a_moved_occurrence_once() {
+        let import = import_ics(
+            &calendar(&[
+                "UID:cours@example.com\r\nDTSTART;VALUE=DATE:20250106\r\nRRULE:FREQ=WEEKLY;COUNT=3\r\n",
+                "UID:cours@example.com\r\nRECURRENCE-ID;VALUE=DATE:20250113\r\nDTSTART;VALUE=DATE:20250115\r\n",
+            ]),
+            &horizon(),
+        );
+        assert_that!(
+            import
+                .periods
+                .into_iter()
+                .map(|imported| imported.period)
+                .collect::<Vec<_>>(),
+            container_eq([
+                range("2025-01-06", "2025-01-06"),
+                range("2025-01-20", "2025-01-20"),
+                range("2025-01-15", "2025-01-15")
+            ])
+        )
+    }
+
+    #[rstest]
+    #[case::exclusive_all_day_end("DTSTART;VALUE=DATE:20250106\r\nDTEND;VALUE=DATE:20250111\r\n")]
+    #[case::inclusive_timed_end("DTSTART:20250106T090000Z\r\nDTEND:20250110T180000Z\r\n")]
+    #[case::time_zone(
+        "DTSTART;TZID=Europe/Paris:20250106T090000\r\nDTEND;TZID=Europe/Paris:20250110T180000\r\n"
+    )]
+    #[case::duration("DTSTART;VALUE=DATE:20250106\r\nDURATION:P5D\r\n")]
+    fn import_a_multi_day_event(#[case] event: &str) {
+        assert_that!(
+            imported_periods(event),
+            container_eq([range("2025-01-06", "2025-01-10")])
+        )
+    }
+
+    #[rstest]
+    fn unfold_and_unescape_the_summary() {
+        let import = import_ics(
+            &calendar(&[
+                "SUMMARY:Vacances de No\r\n ël\\, enfin\r\nDTSTART;VALUE=DATE:20250110\r\n",
+            ]),
+            &horizon(),
+        );
+        assert_that!(
+            import
+                .periods
+                .first()
+                .map(|imported| imported.summary.as_str()),
+            some(eq("Vacances de Noël, enfin"))
+        )
+    }
+
+    #[rstest]
+    #[case::weekly_count("FREQ=WEEKLY;COUNT=3", &["2025-01-06", "2025-01-13", "2025-01-20"])]
+    #[case::daily_until("FREQ=DAILY;INTERVAL=2;UNTIL=20250111", &["2025-01-06", "2025-01-08", "2025-01-10"])]
+    #[case::weekly_by_day("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4", &["2025-01-06", "2025-01-08", "2025-01-13", "2025-01-15"])]
+    #[case::monthly("FREQ=MONTHLY;COUNT=3", &["2025-01-06", "2025-02-06", "2025-03-06"])]
+    #[case::yearly_clipped_by_horizon("FREQ=YEARLY", &["2025-01-06"])]
+    fn expand_recurring_events(#[case] rule: &str, #[case] expected: &[&str]) {
+        let event = format!("DTSTART;VALUE=DATE:20250106\r\nRRULE:{rule}\r\n");
+        let expected: Vec<DateRange> = expected.iter().map(|day| range(day, day)).collect();
+        assert_that!(imported_periods(&event), container_eq(expected))
+    }
+
+    #[rstest]
+    fn skip_excluded_occurrences() {
+        let event = "DTSTART;VALUE=DATE:20250106\r\nRRULE:FREQ=WEEKLY;COUNT=3\r\nEXDATE;VALUE=DATE:20250113\r\n";
+        assert_that!(
+            imported_periods(event),
+            container_eq([
+                range("2025-01-06", "2025-01-06"),
+                range("2025-01-20", "2025-01-20")
+            ])
+        )
+    }
+
+    #[rstest]
+    fn ignore_events_outside_of_the_horizon_and_cancelled_ones() {
+        let import = import_ics(
+            &calendar(&[
+                "DTSTART;VALUE=DATE:20240110\r\n",
+                "DTSTART;VALUE=DATE:20250110\r\nSTATUS:CANCELLED\r\n",
+            ]),
+            &horizon(),
+        );
+        assert_that!(import, eq(&IcsImport::default()))
+    }
+
+    #[rstest]
+    #[case::no_start("SUMMARY:Oups\r\n", IcsImportError::MissingStart)]
+    #[case::invalid_date("DTSTART:2025-01-10\r\n", IcsImportError::InvalidDate("2025-01-10".to_string()))]
+    #[case::end_before_start(
+        "DTSTART;VALUE=DATE:20250110\r\nDTEND;VALUE=DATE:20250101\r\n",
+        IcsImportError::EndBeforeStart
+    )]
+    #[case::unsupported_rule(
+        "DTSTART;VALUE=DATE:20250110\r\nRRULE:FREQ=MONTHLY;BYSETPOS=-1\r\n",
+        IcsImportError::UnsupportedRecurrence("FREQ=MONTHLY;BYSETPOS=-1".to_string())
+    )]
+    #[case::hourly(
+        "DTSTART;VALUE=DATE:20250110\r\nRRULE:FREQ=HOURLY\r\n",
+        IcsImportError::UnsupportedRecurrence("FREQ=HOURLY".to_string())
+    )]
+    #[case::huge_interval(
+        "DTSTART;VALUE=DATE:20250110\r\nRRULE:FREQ=DAILY;INTERVAL=18446744073709551615\r\n",
+        IcsImportError::UnsupportedRecurrence("FREQ=DAILY;INTERVAL=18446744073709551615".to_string())
+    )]
+    #[case::interval_beyond_months(
+        "DTSTART;VALUE=DATE:20250110\r\nRRULE:FREQ=MONTHLY;INTERVAL=4294967296;COUNT=3\r\n",
+        IcsImportError::UnsupportedRecurrence("FREQ=MONTHLY;INTERVAL=4294967296;COUNT=3".to_string())
+    )]
+    #[case::non_ascii_duration(
+        "DTSTART;VALUE=DATE:20250110\r\nDURATION:P1é\r\n",
+        IcsImportError::InvalidDuration("P1é".to_string())
+    )]
+    #[case::end_out_of_range(
+        "DTSTART;VALUE=DATE:20250110\r\nDURATION:P99999999999W\r\n",
+        IcsImportError::EndOutOfRange
+    )]
+    fn report_events_that_cannot_be_mapped(#[case] event: &str, #[case] error: IcsImportError) {
+        let import = import_ics(&calendar(&[event]), &horizon());
+        assert_that!(import.periods, is_empty());
+        assert_that!(
+            import
+                .rejected
+                .into_iter()
+                .map(|rejected| rejected.error)
+                .collect::<Vec<_>>(),
+            container_eq([error])
+        )
+    }
+}
@@ -0,0 +1,381 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, Default)]
+pub enum Locale {
+    #[default]
+    French,
+    English,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum DateParseError {
+    #[error("Unrecognized date format")]
+    UnrecognizedFormat,
+    #[error("Unknown month {0}")]
+    UnknownMonth(String),
+    #[error("Month {0} does not exist")]
+    NonExistentMonth(u32),
+    #[error("Day {day} does not exist in {year}-{month:02}")]
+    NonExistentDay { year: i32, month: u32, day: u32 },
+    #[error("Week {week} does not exist in {year}")]
+    NonExistentWeek { year: i32, week: u32 },
+    #[error("Weekday {0} does not exist, expected 1 (monday) to 7 (sunday)")]
+    NonExistentWeekday(u32),
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::French, Locale::English];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::French => "fr",
+            Locale::English => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::French => "Français",
+            Locale::English => "English",
+        }
+    }
+
+    pub fn first_day_of_week(&self) -> Weekday {
+        match self {
+            Locale::French => Weekday::Mon,
+            Locale::English => Weekday::Sun,
+        }
+    }
+
+    pub fn weekdays(&self) -> [Weekday; 7] {
+        let mut day = self.first_day_of_week();
+        [(); 7].map(|_| {
+            let current = day;
+            day = day.succ();
+            current
+        })
+    }
+
+    pub fn week_start(&self, date: NaiveDate) -> NaiveDate {
+        date - Days::new(date.weekday().days_since(self.first_day_of_week()) as u64)
+    }
+
+    pub fn parse_date(&self, value: &str) -> Result<NaiveDate, DateParseError> {
+        let value = value.trim();
+        parse_iso_week_date(value)
+            .or_else(|| parse_iso_date(value))
+            .or_else(|| self.parse_numeric_date(value))
+            .or_else(|| parse_french_textual_date(value))
+            .unwrap_or(Err(DateParseError::UnrecognizedFormat))
+    }
+
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        match self {
+            Locale::French => date.format("%d/%m/%Y").to_string(),
+            Locale::English => date.format("%m/%d/%Y").to_string(),
+        }
+    }
+
+    pub fn weekday_name(&self, day: Weekday) -> &'static str {
+        match self {
+            Locale::French => FRENCH_WEEKDAY_NAMES[day.num_days_from_monday() as usize],
+            Locale::English => ENGLISH_WEEKDAY_NAMES[day.num_days_from_monday() as usize],
+        }
+    }
+
+    pub fn month_name(&self, month: u32) -> Option<&'static str> {
+        let names = match self {
+            Locale::French => FRENCH_MONTH_NAMES,
+            Locale::English => ENGLISH_MONTH_NAMES,
+        };
+        names.get(month.checked_sub(1)? as usize).copied()
+    }
+
+    fn parse_numeric_date(&self, value: &str) -> Option<Result<NaiveDate, DateParseError>> {
+        let parts: Vec<&str> = value.split(['/', '.', '-']).collect();
+        let [first, second, year] = parts.as_slice() else {
+            return None;
+        };
+        let first = number(first, 1..=2)?;
+        let second = number(second, 1..=2)?;
+        let year = number(year, 4..=4)? as i32;
+        Some(match self {
+            Locale::French => from_ymd(year, second, first),
+            Locale::English => from_ymd(year, first, second),
+        })
+    }
+}
+
+fn number(value: &str, digits: std::ops::RangeInclusive<usize>) -> Option<u32> {
+    if digits.contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn from_ymd(year: i32, month: u32, day: u32) -> Result<NaiveDate, DateParseError> {
+    if !(1..=12).contains(&month) {
+        return Err(DateParseError::NonExistentMonth(month));
+    }
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(DateParseError::NonExistentDay {
+        year,
+        month,
+        day,
+    })
+}
+
+fn parse_iso_date(value: &str) -> Option<Result<NaiveDate, DateParseError>> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return None;
+    };
+    let year = number(year, 4..=4)? as i32;
+    let month = number(month, 1..=2)?;
+    let day = number(day, 1..=2)?;
+    Some(from_ymd(year, month, day))
+}
+
+fn parse_iso_week_date(value: &str) -> Option<Result<NaiveDate, DateParseError>> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, week, weekday] = parts.as_slice() else {
+        return None;
+    };
+    let year = number(year, 4..=4)? as i32;
+    let week = number(week.strip_prefix(['W', 'w'])?, 1..=2)?;
+    let weekday = number(weekday, 1..=1)?;
+    let Some(weekday) = weekday
+        .checked_sub(1)
+        .and_then(|index| Weekday::try_from(index as u8).ok())
+    else {
+        return Some(Err(DateParseError::NonExistentWeekday(weekday)));
+    };
+    Some(
+        NaiveDate::from_isoywd_opt(year, week, weekday)
+            .ok_or(DateParseError::NonExistentWeek { year, week }),
+    )
+}
+
+const FRENCH_WEEKDAY_NAMES: [&str; 7] = [
+    "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche",
+];
+
+const ENGLISH_WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const FRENCH_MONTH_NAMES: [&str; 12] = [
+    "Janvier",
+    "Février",
+    "Mars",
+    "Avril",
+    "Mai",
+    "Juin",
+    "Juillet",
+    "Août",
+    "Septembre",
+    "Octobre",
+    "Novembre",
+    "Décembre",
+];
+
+const ENGLISH_MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const FRENCH_WEEKDAYS: [&str; 7] = [
+    "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+];
+
+const FRENCH_MONTHS: [&[&str]; 12] = [
+    &["janvier", "janv"],
+    &["fevrier", "fevr", "fev"],
+    &["mars"],
+    &["avril", "avr"],
+    &["mai"],
+    &["juin"],
+    &["juillet", "juil"],
+    &["aout"],
+    &["septembre", "sept"],
+    &["octobre", "oct"],
+    &["novembre", "nov"],
+    &["decembre", "dec"],
+];
+
+fn without_accents(value: &str) -> String {
+    value
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' | 'ö' => 'o',
+            'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            c => c,
+        })
+        .collect()
+}
+
+fn parse_french_textual_date(value: &str) -> Option<Result<NaiveDate, DateParseError>> {
+    let mut words: Vec<String> = value
+        .split_whitespace()
+        .map(|word| without_accents(word.trim_end_matches(',')))
+        .collect();
+    if words
+        .first()
+        .is_some_and(|word| FRENCH_WEEKDAYS.contains(&word.as_str()))
+    {
+        words.remove(0);
+    }
+    let [day, month, year] = words.as_slice() else {
+        return None;
+    };
+    let day = number(day.strip_suffix("er").unwrap_or(day), 1..=2)?;
+    let year = number(year, 4..=4)? as i32;
+    let month_name = month.trim_end_matches('.');
+    let Some(month) = FRENCH_MONTHS
+        .iter()
+        .position(|names| names.contains(&month_name))
+    else {
+        return Some(Err(DateParseError::UnknownMonth(month.clone())));
+    };
+    Some(from_ymd(year, month as u32 + 1, day))
+}
+
+#[cfg(test)]
+mod locale_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::iso(Locale::French, "2025-03-10")]
+    #[case::iso_in_english(Locale::English, "2025-03-10")]
+    #[case::french_slashes(Locale::French, "10/03/2025")]
+    #[case::french_dots(Locale::French, "10.03.2025")]
+    #[case::french_single_digits(Locale::French, "10/3/2025")]
+    #[case::english_slashes(Locale::English, "03/10/2025")]
+    #[case::iso_week_date(Locale::French, "2025-W11-1")]
+    #[case::iso_week_date_lowercase(Locale::English, "2025-w11-1")]
+    #[case::french_textual(Locale::French, "10 mars 2025")]
+    #[case::french_textual_with_weekday(Locale::English, "Lundi 10 mars 2025")]
+    #[case::french_textual_capitalized(Locale::French, "10 Mars 2025")]
+    #[case::surrounding_spaces(Locale::French, " 10/03/2025 ")]
+    fn parse_dates(#[case] locale: Locale, #[case] value: &str) {
+        assert_that!(locale.parse_date(value), ok(eq(&date_from("2025-03-10"))))
+    }
+
+    #[rstest]
+    #[case::first_with_suffix("1er février 2025", "2025-02-01")]
+    #[case::without_accent("1 fevrier 2025", "2025-02-01")]
+    #[case::abbreviated("15 déc. 2025", "2025-12-15")]
+    #[case::august("15 août 2025", "2025-08-15")]
+    fn parse_french_textual_months(#[case] value: &str, #[case] expected: &str) {
+        assert_that!(
+            Locale::English.parse_date(value),
+            ok(eq(&date_from(expected)))
+        )
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::wrong_separation("2025/01/01")]
+    #[case::two_digit_year("10/03/25")]
+    #[case::letters("tomorrow")]
+    fn reject_unrecognized_formats(#[case] value: &str) {
+        assert_that!(
+            Locale::French.parse_date(value),
+            err(eq(&DateParseError::UnrecognizedFormat))
+        )
+    }
+
+    #[rstest]
+    #[case::iso_month("2025-13-01", DateParseError::NonExistentMonth(13))]
+    #[case::english_month("13/10/2025", DateParseError::NonExistentMonth(13))]
+    #[case::iso_day("2025-02-30", DateParseError::NonExistentDay { year: 2025, month: 2, day: 30 })]
+    #[case::week("2025-W53-1", DateParseError::NonExistentWeek { year: 2025, week: 53 })]
+    #[case::weekday("2025-W10-8", DateParseError::NonExistentWeekday(8))]
+    #[case::month_name("10 marz 2025", DateParseError::UnknownMonth("marz".to_string()))]
+    fn describe_what_is_wrong(#[case] value: &str, #[case] expected: DateParseError) {
+        assert_that!(Locale::English.parse_date(value), err(eq(&expected)))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, "Lundi")]
+    #[case::english(Locale::English, "Monday")]
+    fn name_weekdays(#[case] locale: Locale, #[case] expected: &str) {
+        assert_that!(locale.weekday_name(Weekday::Mon), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, 8, Some("Août"))]
+    #[case::english(Locale::English, 12, Some("December"))]
+    #[case::zero(Locale::French, 0, None)]
+    #[case::thirteen(Locale::English, 13, None)]
+    fn name_months(#[case] locale: Locale, #[case] month: u32, #[case] expected: Option<&str>) {
+        assert_that!(locale.month_name(month), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun])]
+    #[case::english(Locale::English, [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat])]
+    fn order_weekdays_from_the_first_day_of_week(
+        #[case] locale: Locale,
+        #[case] expected: [Weekday; 7],
+    ) {
+        assert_that!(locale.weekdays(), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french_on_a_wednesday(Locale::French, "2025-03-12", "2025-03-10")]
+    #[case::french_on_a_sunday(Locale::French, "2025-03-16", "2025-03-10")]
+    #[case::english_on_a_wednesday(Locale::English, "2025-03-12", "2025-03-09")]
+    #[case::english_on_a_sunday(Locale::English, "2025-03-16", "2025-03-16")]
+    fn find_the_start_of_the_week(
+        #[case] locale: Locale,
+        #[case] date: &str,
+        #[case] expected: &str,
+    ) {
+        assert_that!(locale.week_start(date_from(date)), eq(date_from(expected)))
+    }
+
+    #[rstest]
+    #[case::french("fr", Some(Locale::French))]
+    #[case::english("en", Some(Locale::English))]
+    #[case::unknown("de", None)]
+    fn find_locales_by_code(#[case] code: &str, #[case] expected: Option<Locale>) {
+        assert_that!(Locale::from_code(code), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, "10/03/2025")]
+    #[case::english(Locale::English, "03/10/2025")]
+    fn format_dates(#[case] locale: Locale, #[case] expected: &str) {
+        assert_that!(locale.format_date(date_from("2025-03-10")), eq(expected))
+    }
+}
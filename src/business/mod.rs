@@ -1,5 +1,20 @@
 pub mod affectations;
+pub mod calendar;
+pub mod csv;
 pub mod date_range;
 pub mod excluded_period_filter;
+pub mod group;
+pub mod history;
+pub mod ics;
+pub mod locale;
 pub mod name;
+pub mod overrides;
+pub mod person;
+pub mod project;
+pub mod recent_files;
+pub mod schedule;
+pub mod share;
+pub mod statistics;
+pub mod table;
+pub mod translations;
 pub mod weekday_filter;
@@ -1,28 +1,163 @@
 use derive_more::with_trait::Display;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
-pub type Names = Vec<Name>;
-
-#[derive(Debug, Eq, PartialEq, Clone, Display)]
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
 #[display("{_0}")]
+#[serde(try_from = "String", into = "String")]
 pub struct Name(String);
 
+const MAX_LENGTH: usize = 50;
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "Vec<Name>", into = "Vec<Name>")]
+pub struct Names {
+    names: Vec<Name>,
+}
+
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
 pub enum NameError {
     #[error("Name is empty")]
     EmptyName,
+    #[error("Name {0} is already taken")]
+    DuplicateName(Name),
+    #[error("Name is {length} characters long, maximum is {max_length}")]
+    TooLong { length: usize, max_length: usize },
+    #[error("Name contains the control character {0:?}")]
+    ControlCharacter(char),
 }
 
-impl TryFrom<String> for Name {
-    type Error = NameError;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        let value = value.trim().to_string();
+impl Name {
+    fn parse(value: &str) -> Result<Self, NameError> {
+        if let Some(c) = value.chars().find(|c| c.is_control() && !c.is_whitespace()) {
+            return Err(NameError::ControlCharacter(c));
+        }
+        let value = value
+            .nfc()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let length = value.chars().count();
         if value.is_empty() {
             Err(NameError::EmptyName)
+        } else if length > MAX_LENGTH {
+            Err(NameError::TooLong {
+                length,
+                max_length: MAX_LENGTH,
+            })
         } else {
             Ok(Name(value))
         }
     }
+
+    pub fn is_similar_to(&self, other: &Name) -> bool {
+        self.comparison_key() == other.comparison_key()
+    }
+
+    fn comparison_key(&self) -> String {
+        self.0
+            .nfd()
+            .filter(|c| !is_combining_mark(*c))
+            .collect::<String>()
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Names {
+    pub fn insert(self, name: Name) -> Result<Self, NameError> {
+        self.check_available(&name, None)?;
+        let mut names = self.names;
+        names.push(name);
+        Ok(Self { names })
+    }
+
+    pub fn replace(self, index: usize, name: Name) -> Result<Self, NameError> {
+        self.check_available(&name, Some(index))?;
+        let mut names = self.names;
+        names[index] = name;
+        Ok(Self { names })
+    }
+
+    pub fn remove(self, index: usize) -> Self {
+        let mut names = self.names;
+        names.remove(index);
+        Self { names }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Name> {
+        self.names.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    fn check_available(&self, name: &Name, ignored: Option<usize>) -> Result<(), NameError> {
+        Self::check_available_among(self.names.iter(), name, ignored)
+    }
+
+    pub(crate) fn check_available_among<'a>(
+        names: impl Iterator<Item = &'a Name>,
+        name: &Name,
+        ignored: Option<usize>,
+    ) -> Result<(), NameError> {
+        let taken = names
+            .enumerate()
+            .any(|(i, existing)| Some(i) != ignored && existing.is_similar_to(name));
+        if taken {
+            Err(NameError::DuplicateName(name.clone()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl TryFrom<Vec<Name>> for Names {
+    type Error = NameError;
+
+    fn try_from(value: Vec<Name>) -> Result<Self, Self::Error> {
+        value
+            .into_iter()
+            .try_fold(Names::default(), |names, name| names.insert(name))
+    }
+}
+
+impl From<Name> for String {
+    fn from(value: Name) -> Self {
+        value.0
+    }
+}
+
+impl From<Names> for Vec<Name> {
+    fn from(value: Names) -> Self {
+        value.names
+    }
+}
+
+impl IntoIterator for Names {
+    type Item = Name;
+    type IntoIter = <Vec<Name> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.into_iter()
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = NameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Name::parse(&value)
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +203,122 @@ mod name_should {
         let name: Result<Name, _> = String::from(name).try_into();
         assert_that!(name, err(eq(&NameError::EmptyName)))
     }
+
+    #[rstest]
+    fn normalize_unicode_to_composed_form() {
+        let decomposed: Result<Name, _> = String::from("He\u{301}le\u{300}ne").try_into();
+        assert_that!(decomposed, ok(eq(&Name::from("Hélène"))))
+    }
+
+    #[rstest]
+    #[case::double_space("Marie  Anne")]
+    #[case::tab("Marie\tAnne")]
+    #[case::non_breaking_space("Marie\u{a0}Anne")]
+    fn collapse_internal_whitespace(#[case] name: &str) {
+        let name: Result<Name, _> = String::from(name).try_into();
+        assert_that!(name, ok(pat!(Name(eq("Marie Anne")))))
+    }
+
+    #[rstest]
+    fn cannot_be_longer_than_the_maximum_length() {
+        let name: Result<Name, _> = "x".repeat(51).try_into();
+        assert_that!(
+            name,
+            err(eq(&NameError::TooLong {
+                length: 51,
+                max_length: 50
+            }))
+        )
+    }
+
+    #[rstest]
+    #[case::null("Xa\u{0}vier", '\u{0}')]
+    #[case::escape("Xavier\u{1b}[31m", '\u{1b}')]
+    #[case::delete("Xavier\u{7f}", '\u{7f}')]
+    fn cannot_contain_control_characters(#[case] name: &str, #[case] control: char) {
+        let name: Result<Name, _> = String::from(name).try_into();
+        assert_that!(name, err(eq(&NameError::ControlCharacter(control))))
+    }
+
+    #[rstest]
+    #[case::same("Xavier", "Xavier")]
+    #[case::case("xavier", "Xavier")]
+    #[case::accent("Hélène", "Helene")]
+    #[case::inner_whitespace("Marie  Anne", "marie anne")]
+    fn be_similar_to_near_duplicates(#[case] name: &str, #[case] other: &str) {
+        assert_that!(
+            Name::from(name).is_similar_to(&Name::from(other)),
+            is_true()
+        )
+    }
+
+    #[rstest]
+    fn not_be_similar_to_a_different_name() {
+        assert_that!(
+            Name::from("Xavier").is_similar_to(&Name::from("Xavière")),
+            is_false()
+        )
+    }
+}
+
+#[cfg(test)]
+mod names_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn keep_names_in_insertion_order() {
+        let names = Names::try_from(vec!["Xavier".into(), "Merve".into()]).unwrap();
+        assert_that!(
+            names.into_iter().collect::<Vec<_>>(),
+            container_eq([Name::from("Xavier"), Name::from("Merve")])
+        )
+    }
+
+    #[rstest]
+    #[case::exact("Xavier")]
+    #[case::different_case("xavier")]
+    #[case::trailing_space("Xavier ")]
+    #[case::accent("Xavièr")]
+    fn reject_duplicates(#[case] duplicate: &str) {
+        let names = Names::default().insert("Xavier".into()).unwrap();
+        assert_that!(
+            names.insert(duplicate.into()),
+            err(eq(&NameError::DuplicateName(duplicate.into())))
+        )
+    }
+
+    #[rstest]
+    fn reject_duplicates_when_built_from_a_list() {
+        let names = Names::try_from(vec!["Xavier".into(), "Merve".into(), "xavier".into()]);
+        assert_that!(names, err(eq(&NameError::DuplicateName("xavier".into()))))
+    }
+
+    #[rstest]
+    fn allow_replacing_a_name_by_a_variant_of_itself() {
+        let names = Names::try_from(vec!["xavier".into(), "Merve".into()]).unwrap();
+        let names = names.replace(0, "Xavier".into()).unwrap();
+        assert_that!(
+            names.iter().collect::<Vec<_>>(),
+            container_eq([&Name::from("Xavier"), &Name::from("Merve")])
+        )
+    }
+
+    #[rstest]
+    fn reject_replacing_a_name_by_another_existing_one() {
+        let names = Names::try_from(vec!["Xavier".into(), "Merve".into()]).unwrap();
+        assert_that!(
+            names.replace(0, "merve".into()),
+            err(eq(&NameError::DuplicateName("merve".into())))
+        )
+    }
+
+    #[rstest]
+    fn can_remove_a_name() {
+        let names = Names::try_from(vec!["Xavier".into(), "Merve".into()]).unwrap();
+        let names = names.remove(0);
+        assert_that!(names.len(), eq(1))
+    }
 }
@@ -0,0 +1,313 @@
+use crate::business::affectations::{Affectation, Assignee};
+use crate::business::name::Name;
+use crate::business::person::{People, Person, PersonId};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Pin {
+    Person(PersonId),
+    Group { name: Name, members: Vec<PersonId> },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Overrides {
+    pinned: BTreeMap<NaiveDate, Pin>,
+}
+
+impl Pin {
+    pub fn resolve(&self, people: &People) -> Option<Assignee> {
+        match self {
+            Pin::Person(id) => people.get(*id).cloned().map(Assignee::Person),
+            Pin::Group { name, members } => {
+                let members: Vec<Person> = members
+                    .iter()
+                    .filter_map(|id| people.get(*id))
+                    .cloned()
+                    .collect();
+                (!members.is_empty()).then(|| Assignee::Group {
+                    name: name.clone(),
+                    members,
+                })
+            }
+        }
+    }
+}
+
+impl From<Assignee> for Pin {
+    fn from(value: Assignee) -> Self {
+        match value {
+            Assignee::Person(person) => Pin::Person(person.id()),
+            Assignee::Group { name, members } => Pin::Group {
+                name,
+                members: members.iter().map(Person::id).collect(),
+            },
+        }
+    }
+}
+
+impl Overrides {
+    pub fn pin(self, date: NaiveDate, assignee: impl Into<Pin>) -> Self {
+        let mut pinned = self.pinned;
+        pinned.insert(date, assignee.into());
+        Self { pinned }
+    }
+
+    pub fn unpin(self, date: &NaiveDate) -> Self {
+        let mut pinned = self.pinned;
+        pinned.remove(date);
+        Self { pinned }
+    }
+
+    pub fn swap(self, affectations: &[Affectation], from: NaiveDate, to: NaiveDate) -> Self {
+        let assignee_on = |date: NaiveDate| {
+            affectations
+                .iter()
+                .find(|affectation| affectation.date == date)
+                .map(|affectation| affectation.assignee.clone())
+        };
+        let (Some(moved), Some(replaced)) = (assignee_on(from), assignee_on(to)) else {
+            return self;
+        };
+        if from == to {
+            return self;
+        }
+        self.pin(from, replaced).pin(to, moved)
+    }
+
+    pub fn reassign(
+        self,
+        affectations: &[Affectation],
+        date: NaiveDate,
+        assignee: impl Into<Pin>,
+    ) -> Self {
+        if affectations
+            .iter()
+            .any(|affectation| affectation.date == date)
+        {
+            self.pin(date, assignee)
+        } else {
+            self
+        }
+    }
+
+    pub fn get(&self, date: &NaiveDate) -> Option<&Pin> {
+        self.pinned.get(date)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&NaiveDate, &Pin)> {
+        self.pinned.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pinned.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pinned.is_empty()
+    }
+
+    pub fn apply(&self, affectations: Vec<Affectation>, people: &People) -> Vec<Affectation> {
+        affectations
+            .into_iter()
+            .map(|affectation| {
+                match self
+                    .pinned
+                    .get(&affectation.date)
+                    .and_then(|pin| pin.resolve(people))
+                {
+                    Some(assignee) => Affectation {
+                        assignee,
+                        ..affectation
+                    },
+                    None => affectation,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod overrides_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::{people, person};
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn affectation(date: &str, id: u64, name: &str) -> Affectation {
+        Affectation {
+            date: date_from(date),
+            assignee: Assignee::Person(person(id, name)),
+        }
+    }
+
+    #[rstest]
+    fn replace_the_assignee_of_pinned_dates() {
+        let overrides = Overrides::default().pin(
+            date_from("2025-01-02"),
+            Assignee::Person(person(1, "Xavier")),
+        );
+        let affectations = overrides.apply(
+            vec![
+                affectation("2025-01-01", 1, "Xavier"),
+                affectation("2025-01-02", 2, "Merve"),
+            ],
+            &people(&["Xavier", "Merve"]),
+        );
+        assert_that!(
+            affectations,
+            container_eq([
+                affectation("2025-01-01", 1, "Xavier"),
+                affectation("2025-01-02", 1, "Xavier")
+            ])
+        )
+    }
+
+    #[rstest]
+    fn follow_the_current_details_of_pinned_people() {
+        let overrides = Overrides::default().pin(
+            date_from("2025-01-01"),
+            Assignee::Person(person(2, "Merve")),
+        );
+        let affectations = overrides.apply(
+            vec![affectation("2025-01-01", 1, "Xavier")],
+            &people(&["Xavier", "Merve Dupont"]),
+        );
+        assert_that!(
+            affectations,
+            container_eq([affectation("2025-01-01", 2, "Merve Dupont")])
+        )
+    }
+
+    #[rstest]
+    fn drop_pins_of_deleted_people() {
+        let overrides = Overrides::default()
+            .pin(
+                date_from("2025-01-01"),
+                Assignee::Person(person(2, "Merve")),
+            )
+            .pin(
+                date_from("2025-01-02"),
+                Assignee::Group {
+                    name: "Team Blue".into(),
+                    members: vec![person(2, "Merve")],
+                },
+            );
+        let affectations = overrides.apply(
+            vec![
+                affectation("2025-01-01", 1, "Xavier"),
+                affectation("2025-01-02", 1, "Xavier"),
+            ],
+            &people(&["Xavier"]),
+        );
+        assert_that!(
+            affectations,
+            container_eq([
+                affectation("2025-01-01", 1, "Xavier"),
+                affectation("2025-01-02", 1, "Xavier")
+            ])
+        )
+    }
+
+    #[rstest]
+    fn ignore_pinned_dates_that_are_not_scheduled() {
+        let overrides = Overrides::default().pin(
+            date_from("2024-12-31"),
+            Assignee::Person(person(2, "Merve")),
+        );
+        let affectations = overrides.apply(
+            vec![affectation("2025-01-01", 1, "Xavier")],
+            &people(&["Xavier", "Merve"]),
+        );
+        assert_that!(
+            affectations,
+            container_eq([affectation("2025-01-01", 1, "Xavier")])
+        )
+    }
+
+    #[rstest]
+    fn swap_the_assignees_of_two_days() {
+        let affectations = [
+            affectation("2025-01-01", 1, "Xavier"),
+            affectation("2025-01-02", 2, "Merve"),
+        ];
+        let overrides = Overrides::default().swap(
+            &affectations,
+            date_from("2025-01-01"),
+            date_from("2025-01-02"),
+        );
+        assert_that!(
+            overrides.apply(affectations.to_vec(), &people(&["Xavier", "Merve"])),
+            container_eq([
+                affectation("2025-01-01", 2, "Merve"),
+                affectation("2025-01-02", 1, "Xavier")
+            ])
+        )
+    }
+
+    #[rstest]
+    fn ignore_drops_on_a_day_without_a_turn() {
+        let overrides = Overrides::default().swap(
+            &[affectation("2025-01-01", 1, "Xavier")],
+            date_from("2025-01-01"),
+            date_from("2025-01-03"),
+        );
+        assert_that!(overrides.is_empty(), is_true())
+    }
+
+    #[rstest]
+    fn ignore_moves_from_a_day_nobody_takes() {
+        let overrides = Overrides::default().swap(
+            &[affectation("2025-01-01", 1, "Xavier")],
+            date_from("2025-01-03"),
+            date_from("2025-01-01"),
+        );
+        assert_that!(overrides.is_empty(), is_true())
+    }
+
+    #[rstest]
+    fn reassign_a_day_to_somebody_else() {
+        let affectations = [
+            affectation("2025-01-01", 1, "Xavier"),
+            affectation("2025-01-02", 2, "Merve"),
+        ];
+        let overrides = Overrides::default().reassign(
+            &affectations,
+            date_from("2025-01-01"),
+            Assignee::Person(person(2, "Merve")),
+        );
+        assert_that!(
+            overrides.apply(affectations.to_vec(), &people(&["Xavier", "Merve"])),
+            container_eq([
+                affectation("2025-01-01", 2, "Merve"),
+                affectation("2025-01-02", 2, "Merve")
+            ])
+        )
+    }
+
+    #[rstest]
+    fn ignore_reassigning_a_day_without_a_turn() {
+        let overrides = Overrides::default().reassign(
+            &[affectation("2025-01-01", 1, "Xavier")],
+            date_from("2025-01-03"),
+            Assignee::Person(person(2, "Merve")),
+        );
+        assert_that!(overrides.is_empty(), is_true())
+    }
+
+    #[rstest]
+    fn can_unpin_a_date() {
+        let overrides = Overrides::default()
+            .pin(
+                date_from("2025-01-02"),
+                Assignee::Person(person(1, "Xavier")),
+            )
+            .unpin(&date_from("2025-01-02"));
+        assert_that!(overrides.is_empty(), is_true())
+    }
+}
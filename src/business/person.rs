@@ -0,0 +1,629 @@
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::name::{Name, NameError, Names};
+use chrono::NaiveDate;
+use derive_more::with_trait::Display;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd, Display, Serialize, Deserialize,
+)]
+#[display("{_0}")]
+#[serde(transparent)]
+pub struct PersonId(u64);
+
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
+#[display("{_0}")]
+#[serde(try_from = "String", into = "String")]
+pub struct Email(String);
+
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
+#[display("{_0}")]
+#[serde(try_from = "String", into = "String")]
+pub struct Phone(String);
+
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
+#[display("{_0}")]
+#[serde(try_from = "String", into = "String")]
+pub struct Color(String);
+
+#[derive(Debug, Eq, PartialEq, Clone, Display, Serialize, Deserialize)]
+#[display("{name}")]
+pub struct Person {
+    id: PersonId,
+    name: Name,
+    email: Option<Email>,
+    phone: Option<Phone>,
+    color: Option<Color>,
+    unavailability: ExcludedPeriodsFilter,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "Vec<Person>", into = "Vec<Person>")]
+pub struct People {
+    people: Vec<Person>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RejectedName {
+    pub entry: String,
+    pub error: NameError,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct PastedNames {
+    pub people: People,
+    pub added: usize,
+    pub rejected: Vec<RejectedName>,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum PersonError {
+    #[error("Invalid email {0}")]
+    InvalidEmail(String),
+    #[error("Invalid phone number {0}")]
+    InvalidPhone(String),
+    #[error("Invalid color {0}, expected #rrggbb")]
+    InvalidColor(String),
+    #[error("{0}")]
+    InvalidName(NameError),
+    #[error("Person id {0} is used more than once")]
+    DuplicateId(PersonId),
+    #[error("Person id {0} is out of range")]
+    IdOutOfRange(PersonId),
+}
+
+impl PersonId {
+    pub fn next_available(people: &People) -> PersonId {
+        PersonId(
+            people
+                .iter()
+                .filter_map(|person| person.id.0.checked_add(1))
+                .max()
+                .unwrap_or(1),
+        )
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for PersonId {
+    fn from(value: u64) -> Self {
+        PersonId(value)
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = PersonError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let valid = value.split_once('@').is_some_and(|(user, domain)| {
+            !user.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !domain.contains('@')
+        }) && !value.contains(char::is_whitespace);
+        if valid {
+            Ok(Email(value.to_string()))
+        } else {
+            Err(PersonError::InvalidEmail(value.to_string()))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+impl From<Phone> for String {
+    fn from(value: Phone) -> Self {
+        value.0
+    }
+}
+
+impl From<Color> for String {
+    fn from(value: Color) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<String> for Phone {
+    type Error = PersonError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let digits = value.chars().filter(char::is_ascii_digit).count();
+        let valid = digits >= 3
+            && value
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-. ()".contains(c));
+        if valid {
+            Ok(Phone(value.to_string()))
+        } else {
+            Err(PersonError::InvalidPhone(value.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = PersonError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_lowercase();
+        let valid = value
+            .strip_prefix('#')
+            .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+        if valid {
+            Ok(Color(value))
+        } else {
+            Err(PersonError::InvalidColor(value))
+        }
+    }
+}
+
+impl Person {
+    pub fn new(id: PersonId, name: Name) -> Self {
+        Person {
+            id,
+            name,
+            email: None,
+            phone: None,
+            color: None,
+            unavailability: ExcludedPeriodsFilter::default(),
+        }
+    }
+
+    pub fn id(&self) -> PersonId {
+        self.id
+    }
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+    pub fn email(&self) -> Option<&Email> {
+        self.email.as_ref()
+    }
+    pub fn phone(&self) -> Option<&Phone> {
+        self.phone.as_ref()
+    }
+    pub fn color(&self) -> Option<&Color> {
+        self.color.as_ref()
+    }
+    pub fn unavailability(&self) -> &ExcludedPeriodsFilter {
+        &self.unavailability
+    }
+
+    pub fn is_available_on(&self, date: &NaiveDate) -> bool {
+        !self.unavailability.excludes(date)
+    }
+
+    pub fn with_name(self, name: Name) -> Self {
+        Person { name, ..self }
+    }
+    pub fn with_email(self, email: Option<Email>) -> Self {
+        Person { email, ..self }
+    }
+    pub fn with_phone(self, phone: Option<Phone>) -> Self {
+        Person { phone, ..self }
+    }
+    pub fn with_color(self, color: Option<Color>) -> Self {
+        Person { color, ..self }
+    }
+    pub fn with_unavailability(self, unavailability: ExcludedPeriodsFilter) -> Self {
+        Person {
+            unavailability,
+            ..self
+        }
+    }
+}
+
+impl People {
+    pub fn get(&self, id: PersonId) -> Option<&Person> {
+        self.people.iter().find(|person| person.id == id)
+    }
+
+    pub fn insert(self, person: Person) -> Result<Self, NameError> {
+        self.check_available(&person.name, None)?;
+        let mut people = self.people;
+        people.push(person);
+        Ok(Self { people })
+    }
+
+    pub fn replace(self, index: usize, person: Person) -> Result<Self, NameError> {
+        self.check_available(&person.name, Some(index))?;
+        let mut people = self.people;
+        people[index] = person;
+        Ok(Self { people })
+    }
+
+    pub fn update(self, person: Person) -> Result<Self, NameError> {
+        match self
+            .people
+            .iter()
+            .position(|existing| existing.id == person.id)
+        {
+            Some(index) => self.replace(index, person),
+            None => Ok(self),
+        }
+    }
+
+    pub fn remove(self, index: usize) -> Self {
+        let mut people = self.people;
+        people.remove(index);
+        Self { people }
+    }
+
+    pub fn paste(self, text: &str) -> PastedNames {
+        text.split(['\n', ',', ';'])
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .fold(
+                PastedNames {
+                    people: self,
+                    ..PastedNames::default()
+                },
+                |pasted, entry| {
+                    let id = PersonId::next_available(&pasted.people);
+                    let inserted = Name::try_from(entry.to_string())
+                        .and_then(|name| pasted.people.clone().insert(Person::new(id, name)));
+                    match inserted {
+                        Ok(people) => PastedNames {
+                            people,
+                            added: pasted.added + 1,
+                            ..pasted
+                        },
+                        Err(error) => {
+                            let mut rejected = pasted.rejected;
+                            rejected.push(RejectedName {
+                                entry: entry.to_string(),
+                                error,
+                            });
+                            PastedNames { rejected, ..pasted }
+                        }
+                    }
+                },
+            )
+    }
+
+    pub fn move_to(self, from: usize, to: usize) -> Self {
+        if from >= self.people.len() || to >= self.people.len() {
+            return self;
+        }
+        let mut people = self.people;
+        let person = people.remove(from);
+        people.insert(to, person);
+        Self { people }
+    }
+
+    pub fn start_with(self, index: usize) -> Self {
+        if index >= self.people.len() {
+            return self;
+        }
+        let mut people = self.people;
+        people.rotate_left(index);
+        Self { people }
+    }
+
+    fn check_available(&self, name: &Name, ignored: Option<usize>) -> Result<(), NameError> {
+        Names::check_available_among(self.people.iter().map(|person| &person.name), name, ignored)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Person> {
+        self.people.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.people.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.people.is_empty()
+    }
+}
+
+impl TryFrom<Vec<Person>> for People {
+    type Error = PersonError;
+
+    fn try_from(value: Vec<Person>) -> Result<Self, Self::Error> {
+        value
+            .into_iter()
+            .try_fold(People::default(), |people, person| {
+                if people.get(person.id).is_some() {
+                    return Err(PersonError::DuplicateId(person.id));
+                }
+                if person.id.0 == u64::MAX {
+                    return Err(PersonError::IdOutOfRange(person.id));
+                }
+                people.insert(person).map_err(PersonError::InvalidName)
+            })
+    }
+}
+
+impl From<People> for Vec<Person> {
+    fn from(value: People) -> Self {
+        value.people
+    }
+}
+
+impl IntoIterator for People {
+    type Item = Person;
+    type IntoIter = <Vec<Person> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.people.into_iter()
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use crate::business::person::{People, Person, PersonId};
+
+    pub fn person(id: u64, name: &str) -> Person {
+        Person::new(PersonId(id), name.into())
+    }
+
+    pub fn people(names: &[&str]) -> People {
+        names
+            .iter()
+            .zip(1..)
+            .map(|(name, id)| person(id, name))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Test values should be valid")
+    }
+}
+
+#[cfg(test)]
+mod person_should {
+    use super::test_helpers::person;
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::date_range::DateRange;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn be_displayed_by_its_name() {
+        assert_that!(person(1, "Xavier").to_string(), eq("Xavier"))
+    }
+
+    #[rstest]
+    fn be_distinct_from_a_homonym() {
+        assert_that!(person(1, "Alex"), not(eq(&person(2, "Alex"))))
+    }
+
+    #[rstest]
+    fn keep_its_identity_when_renamed() {
+        let renamed = person(1, "Alex").with_name("Alexandre".into());
+        assert_that!(renamed.id(), eq(PersonId(1)));
+        assert_that!(renamed.name(), eq(&Name::from("Alexandre")))
+    }
+
+    #[rstest]
+    fn store_contact_details_and_color() {
+        let alex = person(1, "Alex")
+            .with_email(Some(
+                Email::try_from(String::from("alex@example.com")).unwrap(),
+            ))
+            .with_phone(Some(
+                Phone::try_from(String::from("+33 6 12 34 56 78")).unwrap(),
+            ))
+            .with_color(Some(Color::try_from(String::from("#FF8800")).unwrap()));
+        assert_that!(
+            alex.email().map(Email::to_string),
+            some(eq("alex@example.com"))
+        );
+        assert_that!(
+            alex.phone().map(Phone::to_string),
+            some(eq("+33 6 12 34 56 78"))
+        );
+        assert_that!(alex.color().map(Color::to_string), some(eq("#ff8800")))
+    }
+
+    #[rstest]
+    fn be_unavailable_during_their_leave() {
+        let leave = DateRange::try_from(("2025-01-06", "2025-01-10")).unwrap();
+        let alex = person(1, "Alex").with_unavailability(ExcludedPeriodsFilter::from(vec![leave]));
+        assert_that!(alex.is_available_on(&date_from("2025-01-08")), is_false());
+        assert_that!(alex.is_available_on(&date_from("2025-01-13")), is_true())
+    }
+
+    #[rstest]
+    fn get_an_unused_id() {
+        let people = People::try_from(vec![person(3, "Xavier"), person(1, "Merve")]).unwrap();
+        assert_that!(PersonId::next_available(&people), eq(PersonId(4)));
+        assert_that!(
+            PersonId::next_available(&People::default()),
+            eq(PersonId(1))
+        )
+    }
+
+    #[rstest]
+    #[case::no_at("alex.example.com")]
+    #[case::no_user("@example.com")]
+    #[case::no_domain_extension("alex@example")]
+    #[case::two_at("alex@ex@ample.com")]
+    #[case::space("alex @example.com")]
+    fn reject_invalid_emails(#[case] email: &str) {
+        assert_that!(
+            Email::try_from(email.to_string()),
+            err(eq(&PersonError::InvalidEmail(email.to_string())))
+        )
+    }
+
+    #[rstest]
+    #[case::letters("call me")]
+    #[case::too_short("12")]
+    fn reject_invalid_phones(#[case] phone: &str) {
+        assert_that!(
+            Phone::try_from(phone.to_string()),
+            err(eq(&PersonError::InvalidPhone(phone.to_string())))
+        )
+    }
+
+    #[rstest]
+    #[case::no_hash("ff8800")]
+    #[case::too_short("#f80")]
+    #[case::not_hexadecimal("#gg8800")]
+    fn reject_invalid_colors(#[case] color: &str) {
+        assert_that!(
+            Color::try_from(color.to_string()),
+            err(eq(&PersonError::InvalidColor(color.to_string())))
+        )
+    }
+}
+
+#[cfg(test)]
+mod people_should {
+    use super::test_helpers::{people, person};
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn reject_a_person_whose_name_is_taken() {
+        let people = people(&["Xavier", "Merve"]);
+        assert_that!(
+            people.insert(person(3, "xavier ")),
+            err(eq(&NameError::DuplicateName("xavier".into())))
+        )
+    }
+
+    #[rstest]
+    fn refuse_to_deserialize_similar_names() {
+        let json = r#"[
+            {"id": 1, "name": "Xavier", "email": null, "phone": null, "color": null, "unavailability": []},
+            {"id": 2, "name": "xavier", "email": null, "phone": null, "color": null, "unavailability": []}
+        ]"#;
+        assert_that!(serde_json::from_str::<People>(json), err(anything()))
+    }
+
+    #[rstest]
+    fn refuse_people_sharing_an_id() {
+        assert_that!(
+            People::try_from(vec![person(1, "Xavier"), person(1, "Merve")]),
+            err(eq(&PersonError::DuplicateId(PersonId(1))))
+        )
+    }
+
+    #[rstest]
+    fn refuse_an_id_that_leaves_no_room_for_another_person() {
+        assert_that!(
+            People::try_from(vec![person(u64::MAX, "Xavier")]),
+            err(eq(&PersonError::IdOutOfRange(PersonId(u64::MAX))))
+        )
+    }
+
+    #[rstest]
+    fn update_a_person_by_id() {
+        let people = people(&["Xavier", "Merve"]);
+        let merve = person(2, "Merve").with_color(Some(Color("#ff0000".to_string())));
+        let people = people.update(merve.clone()).unwrap();
+        assert_that!(people.get(PersonId(2)), some(eq(&merve)))
+    }
+
+    #[rstest]
+    fn find_a_person_by_id() {
+        let people = people(&["Xavier", "Merve"]);
+        assert_that!(people.get(PersonId(2)), some(eq(&person(2, "Merve"))));
+        assert_that!(people.get(PersonId(3)), none())
+    }
+
+    #[rstest]
+    fn reject_renaming_a_person_to_a_taken_name() {
+        let people = people(&["Xavier", "Merve"]);
+        assert_that!(
+            people.replace(1, person(2, "Xavier")),
+            err(eq(&NameError::DuplicateName("Xavier".into())))
+        )
+    }
+
+    #[rstest]
+    fn accept_updating_a_person_without_renaming_them() {
+        let people = people(&["Xavier", "Merve"]);
+        let merve = person(2, "Merve").with_color(Some(Color("#ff0000".to_string())));
+        let people = people.replace(1, merve.clone()).unwrap();
+        let updated = people.into_iter().nth(1);
+        assert_that!(updated, some(eq(&merve)))
+    }
+
+    fn names_of(people: People) -> Vec<String> {
+        people
+            .into_iter()
+            .map(|person| person.to_string())
+            .collect()
+    }
+
+    #[rstest]
+    #[case::down(0, 2, ["Merve", "Jean", "Xavier", "Ana"])]
+    #[case::up(3, 1, ["Xavier", "Ana", "Merve", "Jean"])]
+    #[case::in_place(1, 1, ["Xavier", "Merve", "Jean", "Ana"])]
+    #[case::out_of_range(1, 4, ["Xavier", "Merve", "Jean", "Ana"])]
+    fn move_a_person_to_another_position(
+        #[case] from: usize,
+        #[case] to: usize,
+        #[case] expected: [&str; 4],
+    ) {
+        let people = people(&["Xavier", "Merve", "Jean", "Ana"]);
+        assert_that!(names_of(people.move_to(from, to)), container_eq(expected))
+    }
+
+    #[rstest]
+    fn rotate_to_start_with_someone_else() {
+        let people = people(&["Xavier", "Merve", "Jean", "Ana"]);
+        assert_that!(
+            names_of(people.start_with(2)),
+            container_eq(["Jean", "Ana", "Xavier", "Merve"])
+        )
+    }
+
+    #[rstest]
+    #[case::lines("Merve\nJean\r\nAna\n")]
+    #[case::commas("Merve, Jean,Ana")]
+    #[case::semicolons("Merve; Jean;;Ana")]
+    fn add_a_pasted_list_in_order(#[case] text: &str) {
+        let pasted = people(&["Xavier"]).paste(text);
+        assert_that!(pasted.added, eq(3));
+        assert_that!(pasted.rejected, is_empty());
+        assert_that!(
+            names_of(pasted.people),
+            container_eq(["Xavier", "Merve", "Jean", "Ana"])
+        )
+    }
+
+    #[rstest]
+    fn report_rejected_and_duplicate_entries() {
+        let too_long = "a".repeat(51);
+        let pasted = people(&["Xavier"]).paste(&format!("Merve\nxavier\n{too_long}\nJean\nmerve"));
+        assert_that!(
+            names_of(pasted.people),
+            container_eq(["Xavier", "Merve", "Jean"])
+        );
+        assert_that!(
+            pasted.rejected,
+            container_eq([
+                RejectedName {
+                    entry: "xavier".to_string(),
+                    error: NameError::DuplicateName("xavier".into())
+                },
+                RejectedName {
+                    entry: too_long,
+                    error: NameError::TooLong {
+                        length: 51,
+                        max_length: 50
+                    }
+                },
+                RejectedName {
+                    entry: "merve".to_string(),
+                    error: NameError::DuplicateName("merve".into())
+                },
+            ])
+        )
+    }
+}
@@ -0,0 +1,296 @@
+use crate::business::affectations::{AbsencePolicy, Affectation};
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::group::Group;
+use crate::business::overrides::Overrides;
+use crate::business::person::People;
+use crate::business::schedule::Schedule;
+use crate::business::weekday_filter::WeekDayFilter;
+use chrono::{DateTime, Utc};
+use derive_more::with_trait::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+pub const CURRENT_VERSION: u64 = 2;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default, Display, Serialize, Deserialize)]
+#[display("{_0:016x}")]
+#[serde(transparent)]
+pub struct ScheduleId(u64);
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Project {
+    pub id: ScheduleId,
+    pub people: People,
+    pub groups: Vec<Group>,
+    pub range: Option<DateRange>,
+    pub weekday_filter: WeekDayFilter,
+    pub excluded_periods: ExcludedPeriodsFilter,
+    pub absence_policy: AbsencePolicy,
+    pub overrides: Overrides,
+    pub affectations: Vec<Affectation>,
+}
+
+#[derive(Serialize)]
+struct VersionedProject<'a> {
+    version: u64,
+    #[serde(flatten)]
+    project: &'a Project,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum ProjectError {
+    #[error("Project file is not valid JSON: {0}")]
+    InvalidJson(String),
+    #[error("Project file has no format version")]
+    MissingVersion,
+    #[error("Project format version {0} is not between 1 and {CURRENT_VERSION}")]
+    UnsupportedVersion(u64),
+    #[error("Project file version {version} cannot be migrated: {reason}")]
+    MigrationFailed { version: u64, reason: String },
+    #[error("Project file content is invalid: {0}")]
+    InvalidContent(String),
+}
+
+impl ScheduleId {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        ScheduleId(now.timestamp_micros() as u64)
+    }
+
+    pub fn or_new(self, now: DateTime<Utc>) -> Self {
+        if self == ScheduleId::default() {
+            ScheduleId::new(now)
+        } else {
+            self
+        }
+    }
+}
+
+impl From<u64> for ScheduleId {
+    fn from(value: u64) -> Self {
+        ScheduleId(value)
+    }
+}
+
+impl Project {
+    pub fn generate_affectations(&self) -> Vec<Affectation> {
+        Schedule::from(self).plan().affectations
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.versioned()).expect("Projects are always serializable")
+    }
+
+    pub fn to_compact_json(&self) -> String {
+        serde_json::to_string(&self.versioned()).expect("Projects are always serializable")
+    }
+
+    fn versioned(&self) -> VersionedProject<'_> {
+        VersionedProject {
+            version: CURRENT_VERSION,
+            project: self,
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ProjectError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| ProjectError::InvalidJson(e.to_string()))?;
+        let mut value = match value {
+            Value::Object(object) => object,
+            _ => {
+                return Err(ProjectError::InvalidContent(
+                    "expected an object".to_string(),
+                ))
+            }
+        };
+        let version = value
+            .remove("version")
+            .and_then(|version| version.as_u64())
+            .ok_or(ProjectError::MissingVersion)?;
+        if !(1..=CURRENT_VERSION).contains(&version) {
+            return Err(ProjectError::UnsupportedVersion(version));
+        }
+        let migrated = (version..CURRENT_VERSION).try_fold(value, |value, version| {
+            migrate(version, value)
+                .map_err(|reason| ProjectError::MigrationFailed { version, reason })
+        })?;
+        serde_json::from_value(Value::Object(migrated))
+            .map_err(|e| ProjectError::InvalidContent(e.to_string()))
+    }
+}
+
+fn migrate(version: u64, project: Map<String, Value>) -> Result<Map<String, Value>, String> {
+    match version {
+        1 => Ok(with_unset_schedule_id(project)),
+        _ => Err(format!("unknown version {version}")),
+    }
+}
+
+fn with_unset_schedule_id(mut project: Map<String, Value>) -> Map<String, Value> {
+    project.insert("id".to_string(), json!(ScheduleId::default()));
+    project
+}
+
+#[cfg(test)]
+mod project_should {
+    use super::*;
+    use crate::business::affectations::Assignee;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::{people, person};
+    use crate::business::person::{Color, Email};
+    use chrono::Weekday::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn project() -> Project {
+        let xavier = person(1, "Xavier")
+            .with_email(Some(
+                Email::try_from(String::from("xavier@example.com")).unwrap(),
+            ))
+            .with_color(Some(Color::try_from(String::from("#ff8800")).unwrap()))
+            .with_unavailability(ExcludedPeriodsFilter::from(vec![DateRange::try_from((
+                "2025-01-06",
+                "2025-01-10",
+            ))
+            .unwrap()]));
+        let people = People::try_from(vec![xavier.clone(), person(2, "Merve")]).unwrap();
+        Project {
+            id: 42.into(),
+            groups: vec![Group::new("Team Blue".into())
+                .toggle_member(1.into())
+                .toggle_member(2.into())],
+            range: Some(("2025-01-01", "2025-01-31").try_into().unwrap()),
+            weekday_filter: WeekDayFilter::default().toggle(Fri),
+            excluded_periods: ExcludedPeriodsFilter::from(vec![DateRange::try_from((
+                "2025-01-20",
+                "2025-01-24",
+            ))
+            .unwrap()]),
+            absence_policy: AbsencePolicy::Skip,
+            overrides: Overrides::default()
+                .pin(date_from("2025-01-02"), Assignee::Person(xavier.clone())),
+            affectations: vec![Affectation {
+                date: date_from("2025-01-02"),
+                assignee: Assignee::Person(xavier),
+            }],
+            people,
+        }
+    }
+
+    #[rstest]
+    fn generate_affectations_from_its_settings() {
+        let project = Project {
+            people: people(&["Xavier", "Merve"]),
+            range: Some(("2025-01-06", "2025-01-10").try_into().unwrap()),
+            weekday_filter: WeekDayFilter::from(vec![Mon, Wed, Fri]),
+            ..Project::default()
+        };
+        assert_that!(
+            project.generate_affectations(),
+            container_eq([
+                Affectation {
+                    date: date_from("2025-01-06"),
+                    assignee: Assignee::Person(person(1, "Xavier"))
+                },
+                Affectation {
+                    date: date_from("2025-01-08"),
+                    assignee: Assignee::Person(person(2, "Merve"))
+                },
+                Affectation {
+                    date: date_from("2025-01-10"),
+                    assignee: Assignee::Person(person(1, "Xavier"))
+                },
+            ])
+        )
+    }
+
+    #[rstest]
+    fn generate_nothing_without_a_range() {
+        let project = Project {
+            people: people(&["Xavier"]),
+            ..Project::default()
+        };
+        assert_that!(project.generate_affectations(), is_empty())
+    }
+
+    #[rstest]
+    fn round_trip_through_json() {
+        let project = project();
+        assert_that!(Project::from_json(&project.to_json()), ok(eq(&project)))
+    }
+
+    #[rstest]
+    fn migrate_a_version_1_file_without_schedule_id() {
+        let mut json: Value = serde_json::from_str(&project().to_json()).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("id");
+        object.insert("version".to_string(), json!(1));
+        assert_that!(
+            Project::from_json(&json.to_string()),
+            ok(eq(&Project {
+                id: ScheduleId::default(),
+                ..project()
+            }))
+        )
+    }
+
+    #[rstest]
+    fn report_versions_without_migration() {
+        assert_that!(
+            migrate(CURRENT_VERSION, Map::new()),
+            err(eq("unknown version 2"))
+        )
+    }
+
+    #[rstest]
+    fn write_the_current_version() {
+        let json: Value = serde_json::from_str(&Project::default().to_json()).unwrap();
+        assert_that!(json["version"], eq(&json!(CURRENT_VERSION)))
+    }
+
+    #[rstest]
+    fn write_dates_and_weekdays_readably() {
+        let json: Value = serde_json::from_str(&project().to_json()).unwrap();
+        assert_that!(
+            json["range"],
+            eq(&json!({"start": "2025-01-01", "end": "2025-01-31"}))
+        );
+        assert_that!(json["weekday_filter"], eq(&json!(["Mon", "Tue", "Thu"])))
+    }
+
+    #[rstest]
+    fn reject_files_that_are_not_json() {
+        assert_that!(
+            Project::from_json("{"),
+            err(pat!(ProjectError::InvalidJson(_)))
+        )
+    }
+
+    #[rstest]
+    #[case::no_version("{}", ProjectError::MissingVersion)]
+    #[case::future_version(r#"{"version": 99}"#, ProjectError::UnsupportedVersion(99))]
+    #[case::version_zero(r#"{"version": 0}"#, ProjectError::UnsupportedVersion(0))]
+    fn reject_unknown_versions(#[case] json: &str, #[case] expected: ProjectError) {
+        assert_that!(Project::from_json(json), err(eq(&expected)))
+    }
+
+    #[rstest]
+    #[case::reversed_range(r#""range": {"start": "2025-02-01", "end": "2025-01-01"}"#)]
+    #[case::empty_name(r#""people": [{"id": 1, "name": " ", "email": null, "phone": null, "color": null, "unavailability": []}]"#)]
+    #[case::duplicate_names(r#""people": [{"id": 1, "name": "Xavier", "email": null, "phone": null, "color": null, "unavailability": []}, {"id": 2, "name": "xavier", "email": null, "phone": null, "color": null, "unavailability": []}]"#)]
+    #[case::duplicate_ids(r#""people": [{"id": 1, "name": "Xavier", "email": null, "phone": null, "color": null, "unavailability": []}, {"id": 1, "name": "Merve", "email": null, "phone": null, "color": null, "unavailability": []}]"#)]
+    #[case::maximum_id(r#""people": [{"id": 18446744073709551615, "name": "Xavier", "email": null, "phone": null, "color": null, "unavailability": []}]"#)]
+    #[case::invalid_email(r#""people": [{"id": 1, "name": "Xavier", "email": "nope", "phone": null, "color": null, "unavailability": []}]"#)]
+    fn validate_content_on_load(#[case] field: &str) {
+        let mut json: Value = serde_json::from_str(&Project::default().to_json()).unwrap();
+        let field: Value = serde_json::from_str(&format!("{{{field}}}")).unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .extend(field.as_object().unwrap().clone());
+        assert_that!(
+            Project::from_json(&json.to_string()),
+            err(pat!(ProjectError::InvalidContent(_)))
+        )
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const MAX_RECENT_FILES: usize = 8;
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RecentFiles {
+    paths: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    pub fn open(self, path: PathBuf) -> Self {
+        let mut paths = self.forget(&path).paths;
+        paths.insert(0, path);
+        paths.truncate(MAX_RECENT_FILES);
+        Self { paths }
+    }
+
+    pub fn forget(self, path: &Path) -> Self {
+        let mut paths = self.paths;
+        paths.retain(|recent| recent != path);
+        Self { paths }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PathBuf> {
+        self.paths.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod recent_files_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn list_the_last_opened_file_first() {
+        let recent = RecentFiles::default()
+            .open(PathBuf::from("a.json"))
+            .open(PathBuf::from("b.json"));
+        assert_that!(recent, eq(&recent_files(&["b.json", "a.json"])))
+    }
+
+    #[rstest]
+    fn move_a_reopened_file_to_the_top() {
+        let recent = recent_files(&["a.json", "b.json", "c.json"]).open(PathBuf::from("c.json"));
+        assert_that!(recent, eq(&recent_files(&["c.json", "a.json", "b.json"])))
+    }
+
+    #[rstest]
+    fn remember_a_limited_number_of_files() {
+        let recent = (0..20).fold(RecentFiles::default(), |recent, index| {
+            recent.open(PathBuf::from(format!("{index}.json")))
+        });
+        assert_that!(recent.iter().count(), eq(MAX_RECENT_FILES));
+        assert_that!(recent.iter().next(), some(eq(&PathBuf::from("19.json"))));
+    }
+
+    #[rstest]
+    fn forget_a_file() {
+        let recent = recent_files(&["a.json", "b.json"]).forget(Path::new("a.json"));
+        assert_that!(recent, eq(&recent_files(&["b.json"])))
+    }
+
+    fn recent_files(paths: &[&str]) -> RecentFiles {
+        RecentFiles {
+            paths: paths.iter().map(PathBuf::from).collect(),
+        }
+    }
+}
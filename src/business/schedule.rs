@@ -0,0 +1,268 @@
+use crate::business::affectations::{create_affectations, AbsencePolicy, Affectation, Assignee};
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::{ExcludePeriods, ExcludedPeriodsFilter};
+use crate::business::group::Group;
+use crate::business::overrides::Overrides;
+use crate::business::person::People;
+use crate::business::project::Project;
+use crate::business::weekday_filter::{FilterByWeekDays, WeekDayFilter};
+use chrono::NaiveDate;
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Schedule {
+    pub people: People,
+    pub groups: Vec<Group>,
+    pub range: Option<DateRange>,
+    pub weekday_filter: WeekDayFilter,
+    pub excluded_periods: ExcludedPeriodsFilter,
+    pub absence_policy: AbsencePolicy,
+    pub overrides: Overrides,
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq, Clone)]
+pub enum ScheduleWarning {
+    #[error("No period to plan")]
+    MissingRange,
+    #[error("Nobody to assign")]
+    NobodyToAssign,
+    #[error("Team {0} has no member")]
+    EmptyGroup(String),
+    #[error("No day is left once weekdays and excluded periods are filtered out")]
+    NoDayLeft,
+    #[error("Nobody is available on {0} day(s)")]
+    UnassignedDays(usize),
+    #[error("{0} pinned date(s) fall outside the planned days or name somebody removed")]
+    IgnoredPins(usize),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Plan {
+    pub affectations: Vec<Affectation>,
+    pub warnings: Vec<ScheduleWarning>,
+}
+
+impl Schedule {
+    pub fn plan(&self) -> Plan {
+        let teams: Vec<(&Group, Option<Assignee>)> = self
+            .groups
+            .iter()
+            .map(|group| (group, group.resolve(&self.people)))
+            .collect();
+        let assignees: Vec<Assignee> = if self.groups.is_empty() {
+            Assignee::individuals(self.people.clone())
+        } else {
+            teams
+                .iter()
+                .filter_map(|(_, assignee)| assignee.clone())
+                .collect()
+        };
+        let mut warnings = vec![];
+        if self.range.is_none() {
+            warnings.push(ScheduleWarning::MissingRange);
+        }
+        if assignees.is_empty() {
+            warnings.push(ScheduleWarning::NobodyToAssign);
+        }
+        warnings.extend(
+            teams
+                .iter()
+                .filter(|(_, assignee)| assignee.is_none())
+                .map(|(group, _)| ScheduleWarning::EmptyGroup(group.name().to_string())),
+        );
+        let Some(range) = self.range else {
+            return Plan {
+                affectations: vec![],
+                warnings,
+            };
+        };
+        let days: Vec<NaiveDate> = range
+            .into_iter()
+            .filter_by_weekday(&self.weekday_filter)
+            .exclude_period(&self.excluded_periods)
+            .collect();
+        let nobody = assignees.is_empty();
+        let generated = create_affectations(assignees, days.clone(), self.absence_policy);
+        if days.is_empty() {
+            warnings.push(ScheduleWarning::NoDayLeft);
+        } else if !nobody && generated.len() < days.len() {
+            warnings.push(ScheduleWarning::UnassignedDays(
+                days.len() - generated.len(),
+            ));
+        }
+        let ignored = self
+            .overrides
+            .iter()
+            .filter(|(date, pin)| {
+                pin.resolve(&self.people).is_none()
+                    || !generated
+                        .iter()
+                        .any(|affectation| affectation.date == **date)
+            })
+            .count();
+        if ignored > 0 {
+            warnings.push(ScheduleWarning::IgnoredPins(ignored));
+        }
+        Plan {
+            affectations: self.overrides.apply(generated, &self.people),
+            warnings,
+        }
+    }
+}
+
+impl From<&Project> for Schedule {
+    fn from(project: &Project) -> Self {
+        Schedule {
+            people: project.people.clone(),
+            groups: project.groups.clone(),
+            range: project.range,
+            weekday_filter: project.weekday_filter.clone(),
+            excluded_periods: project.excluded_periods.clone(),
+            absence_policy: project.absence_policy,
+            overrides: project.overrides.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod schedule_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::{people, person};
+    use crate::business::person::{Person, PersonId};
+    use chrono::Weekday;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn schedule() -> Schedule {
+        Schedule {
+            people: people(&["Xavier", "Merve"]),
+            range: Some(("2025-01-06", "2025-01-12").try_into().unwrap()),
+            ..Schedule::default()
+        }
+    }
+
+    fn dates(plan: &Plan) -> Vec<NaiveDate> {
+        plan.affectations
+            .iter()
+            .map(|affectation| affectation.date)
+            .collect()
+    }
+
+    #[rstest]
+    fn plan_every_accepted_day_of_the_range_without_warning() {
+        let plan = schedule().plan();
+        assert_that!(plan.affectations.len(), eq(4));
+        assert_that!(plan.warnings, is_empty());
+    }
+
+    #[rstest]
+    fn chain_the_weekday_filter_and_the_exclusions() {
+        let plan = Schedule {
+            weekday_filter: WeekDayFilter::from(vec![Weekday::Mon, Weekday::Tue]),
+            excluded_periods: ExcludedPeriodsFilter::default()
+                .insert(("2025-01-07", "2025-01-07").try_into().unwrap()),
+            ..schedule()
+        }
+        .plan();
+        assert_that!(dates(&plan), container_eq([date_from("2025-01-06")]))
+    }
+
+    #[rstest]
+    fn apply_pinned_dates() {
+        let merve = Assignee::Person(person(2, "Merve"));
+        let plan = Schedule {
+            overrides: Overrides::default().pin(date_from("2025-01-06"), merve.clone()),
+            ..schedule()
+        }
+        .plan();
+        assert_that!(plan.affectations[0].assignee, eq(&merve))
+    }
+
+    #[rstest]
+    #[case::missing_range(
+        Schedule { range: None, ..schedule() },
+        vec![ScheduleWarning::MissingRange]
+    )]
+    #[case::nobody(
+        Schedule { people: People::default(), ..schedule() },
+        vec![ScheduleWarning::NobodyToAssign]
+    )]
+    #[case::no_day_left(
+        Schedule {
+            excluded_periods: ExcludedPeriodsFilter::default()
+                .insert(("2025-01-01", "2025-01-31").try_into().unwrap()),
+            ..schedule()
+        },
+        vec![ScheduleWarning::NoDayLeft]
+    )]
+    #[case::ignored_pins(
+        Schedule {
+            overrides: Overrides::default()
+                .pin(date_from("2025-02-01"), Assignee::Person(person(1, "Xavier"))),
+            ..schedule()
+        },
+        vec![ScheduleWarning::IgnoredPins(1)]
+    )]
+    #[case::pin_of_a_deleted_person(
+        Schedule {
+            overrides: Overrides::default()
+                .pin(date_from("2025-01-06"), Assignee::Person(person(3, "Jean"))),
+            ..schedule()
+        },
+        vec![ScheduleWarning::IgnoredPins(1)]
+    )]
+    fn warn_about_inconsistent_settings(
+        #[case] schedule: Schedule,
+        #[case] expected: Vec<ScheduleWarning>,
+    ) {
+        assert_that!(schedule.plan().warnings, container_eq(expected))
+    }
+
+    #[rstest]
+    fn give_no_turn_to_a_team_without_members() {
+        let plan = Schedule {
+            groups: vec![
+                Group::new("Team Blue".into()).toggle_member(PersonId::from(1)),
+                Group::new("Team Red".into()),
+            ],
+            ..schedule()
+        }
+        .plan();
+        assert_that!(
+            plan.affectations
+                .iter()
+                .map(|affectation| affectation.assignee.name().to_string())
+                .collect::<Vec<_>>(),
+            each(eq("Team Blue"))
+        );
+        assert_that!(plan.affectations.len(), eq(4));
+        assert_that!(
+            plan.warnings,
+            container_eq([ScheduleWarning::EmptyGroup("Team Red".to_string())])
+        )
+    }
+
+    #[rstest]
+    fn count_the_days_nobody_can_take() {
+        let absent = |person: Person| {
+            person.with_unavailability(
+                ExcludedPeriodsFilter::default()
+                    .insert(("2025-01-06", "2025-01-07").try_into().unwrap()),
+            )
+        };
+        let people: People = vec![absent(person(1, "Xavier")), absent(person(2, "Merve"))]
+            .try_into()
+            .unwrap();
+        let plan = Schedule {
+            people,
+            ..schedule()
+        }
+        .plan();
+        assert_that!(plan.affectations.len(), eq(2));
+        assert_that!(
+            plan.warnings,
+            container_eq([ScheduleWarning::UnassignedDays(2)])
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::business::affectations::AbsencePolicy;
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::group::Group;
+use crate::business::name::Name;
+use crate::business::overrides::Overrides;
+use crate::business::person::{Color, People, Person, PersonId};
+use crate::business::project::Project;
+use crate::business::weekday_filter::WeekDayFilter;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+const LINK_VERSION: &str = "1";
+
+#[derive(Serialize, Deserialize)]
+struct SharedSchedule {
+    people: Vec<SharedPerson>,
+    groups: Vec<Group>,
+    range: Option<DateRange>,
+    weekday_filter: WeekDayFilter,
+    excluded_periods: ExcludedPeriodsFilter,
+    absence_policy: AbsencePolicy,
+    overrides: Overrides,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SharedPerson {
+    id: PersonId,
+    name: Name,
+    color: Option<Color>,
+    unavailability: ExcludedPeriodsFilter,
+}
+
+impl From<&Person> for SharedPerson {
+    fn from(person: &Person) -> Self {
+        SharedPerson {
+            id: person.id(),
+            name: person.name().clone(),
+            color: person.color().cloned(),
+            unavailability: person.unavailability().clone(),
+        }
+    }
+}
+
+impl From<SharedPerson> for Person {
+    fn from(person: SharedPerson) -> Self {
+        Person::new(person.id, person.name)
+            .with_color(person.color)
+            .with_unavailability(person.unavailability)
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+pub enum ShareError {
+    #[error("The link does not contain a schedule")]
+    MissingSchedule,
+    #[error("The link format {0} is not supported")]
+    UnsupportedLinkVersion(String),
+    #[error("The link is truncated or corrupted")]
+    CorruptedLink,
+    #[error("The link content is invalid: {0}")]
+    InvalidSchedule(String),
+}
+
+pub fn encode_fragment(project: &Project) -> String {
+    let shared = SharedSchedule {
+        people: project.people.iter().map(SharedPerson::from).collect(),
+        groups: project.groups.clone(),
+        range: project.range,
+        weekday_filter: project.weekday_filter.clone(),
+        excluded_periods: project.excluded_periods.clone(),
+        absence_policy: project.absence_policy,
+        overrides: project.overrides.clone(),
+    };
+    let json = serde_json::to_string(&shared).expect("Shared schedules are always serializable");
+    format!("{LINK_VERSION}.{}", URL_SAFE_NO_PAD.encode(json))
+}
+
+pub fn decode_fragment(fragment: &str) -> Result<Project, ShareError> {
+    let fragment = fragment.trim_start_matches('#');
+    let Some((version, payload)) = fragment.split_once('.') else {
+        return Err(ShareError::MissingSchedule);
+    };
+    if version != LINK_VERSION {
+        return Err(ShareError::UnsupportedLinkVersion(version.to_string()));
+    }
+    let json = URL_SAFE_NO_PAD
+        .decode(payload)
+        .ok()
+        .and_then(|json| String::from_utf8(json).ok())
+        .ok_or(ShareError::CorruptedLink)?;
+    let shared: SharedSchedule =
+        serde_json::from_str(&json).map_err(|e| ShareError::InvalidSchedule(e.to_string()))?;
+    let people = shared
+        .people
+        .into_iter()
+        .map(Person::from)
+        .collect::<Vec<_>>();
+    Ok(Project {
+        people: People::try_from(people).map_err(|e| ShareError::InvalidSchedule(e.to_string()))?,
+        groups: shared.groups,
+        range: shared.range,
+        weekday_filter: shared.weekday_filter,
+        excluded_periods: shared.excluded_periods,
+        absence_policy: shared.absence_policy,
+        overrides: shared.overrides,
+        ..Project::default()
+    })
+}
+
+#[cfg(test)]
+mod share_should {
+    use super::*;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::overrides::Pin;
+    use crate::business::person::test_helpers::person;
+    use crate::business::person::{Email, Phone};
+    use chrono::Weekday::Wed;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn project() -> Project {
+        let xavier = person(1, "Xavier")
+            .with_color(Some(Color::try_from(String::from("#ff8800")).unwrap()))
+            .with_unavailability(ExcludedPeriodsFilter::from(vec![DateRange::try_from((
+                "2025-01-06",
+                "2025-01-10",
+            ))
+            .unwrap()]));
+        Project {
+            people: People::try_from(vec![xavier, person(3, "Merve")]).unwrap(),
+            groups: vec![Group::new("Team Blue".into()).toggle_member(3.into())],
+            range: Some(("2025-01-01", "2025-01-31").try_into().unwrap()),
+            weekday_filter: WeekDayFilter::default().toggle(Wed),
+            absence_policy: AbsencePolicy::Skip,
+            overrides: Overrides::default().pin(date_from("2025-01-02"), Pin::Person(3.into())),
+            ..Project::default()
+        }
+    }
+
+    #[rstest]
+    fn round_trip_through_a_fragment() {
+        assert_that!(
+            decode_fragment(&encode_fragment(&project())),
+            ok(eq(&project()))
+        )
+    }
+
+    #[rstest]
+    fn leave_contact_details_out_of_the_link() {
+        let xavier = person(1, "Xavier")
+            .with_email(Some(
+                Email::try_from(String::from("xavier@example.com")).unwrap(),
+            ))
+            .with_phone(Some(
+                Phone::try_from(String::from("+33 6 12 34 56 78")).unwrap(),
+            ));
+        let project = Project {
+            people: People::try_from(vec![xavier]).unwrap(),
+            ..project()
+        };
+        let fragment = encode_fragment(&project);
+        let payload = fragment.split_once('.').unwrap().1;
+        let json = String::from_utf8(URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+        assert_that!(json, not(contains_substring("xavier@example.com")));
+        assert_that!(json, not(contains_substring("+33")));
+        assert_that!(json, not(contains_substring("email")));
+        assert_that!(json, not(contains_substring("phone")))
+    }
+
+    #[rstest]
+    fn produce_url_safe_fragments() {
+        assert_that!(
+            encode_fragment(&project())
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c)),
+            is_true()
+        )
+    }
+
+    #[rstest]
+    fn accept_a_leading_hash() {
+        let fragment = format!("#{}", encode_fragment(&project()));
+        assert_that!(decode_fragment(&fragment), ok(eq(&project())))
+    }
+
+    #[rstest]
+    #[case::empty("", ShareError::MissingSchedule)]
+    #[case::future_link("2.abc", ShareError::UnsupportedLinkVersion("2".to_string()))]
+    #[case::not_base64("1.not base64!", ShareError::CorruptedLink)]
+    fn explain_invalid_links(#[case] fragment: &str, #[case] expected: ShareError) {
+        assert_that!(decode_fragment(fragment), err(eq(&expected)))
+    }
+
+    #[rstest]
+    fn reject_links_with_duplicate_names() {
+        let json = r#"{"people":[{"id":1,"name":"Xavier","color":null,"unavailability":[]},{"id":2,"name":"xavier","color":null,"unavailability":[]}],"groups":[],"range":null,"weekday_filter":[],"excluded_periods":[],"absence_policy":"skip","overrides":{}}"#;
+        let fragment = format!("1.{}", URL_SAFE_NO_PAD.encode(json));
+        assert_that!(
+            decode_fragment(&fragment),
+            err(pat!(ShareError::InvalidSchedule(_)))
+        )
+    }
+}
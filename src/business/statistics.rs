@@ -0,0 +1,223 @@
+use crate::business::affectations::Affectation;
+use crate::business::group::Group;
+use crate::business::person::{People, Person};
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PersonStatistics {
+    pub person: Person,
+    pub assignable: bool,
+    pub turns: usize,
+    pub per_weekday: [usize; 7],
+    pub per_month: BTreeMap<(i32, u32), usize>,
+    pub longest_gap: Option<i64>,
+    pub shortest_gap: Option<i64>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Statistics {
+    pub people: Vec<PersonStatistics>,
+}
+
+impl Statistics {
+    pub fn new(people: &People, groups: &[Group], affectations: &[Affectation]) -> Self {
+        let people = people
+            .iter()
+            .map(|person| {
+                let assignable = groups.is_empty()
+                    || groups
+                        .iter()
+                        .any(|group| group.members().contains(&person.id()));
+                let dates: Vec<NaiveDate> = affectations
+                    .iter()
+                    .filter(|affectation| {
+                        affectation
+                            .assignee
+                            .people()
+                            .iter()
+                            .any(|member| member.id() == person.id())
+                    })
+                    .map(|affectation| affectation.date)
+                    .collect();
+                let mut per_weekday = [0; 7];
+                let mut per_month = BTreeMap::new();
+                for date in &dates {
+                    per_weekday[date.weekday().num_days_from_monday() as usize] += 1;
+                    *per_month.entry((date.year(), date.month())).or_insert(0) += 1;
+                }
+                let gaps: Vec<i64> = dates
+                    .windows(2)
+                    .map(|pair| (pair[1] - pair[0]).num_days())
+                    .collect();
+                PersonStatistics {
+                    person: person.clone(),
+                    assignable,
+                    turns: dates.len(),
+                    per_weekday,
+                    per_month,
+                    longest_gap: gaps.iter().max().copied(),
+                    shortest_gap: gaps.iter().min().copied(),
+                }
+            })
+            .collect();
+        Statistics { people }
+    }
+
+    pub fn months(&self) -> Vec<(i32, u32)> {
+        let mut months: Vec<(i32, u32)> = self
+            .people
+            .iter()
+            .flat_map(|statistics| statistics.per_month.keys().copied())
+            .collect();
+        months.sort();
+        months.dedup();
+        months
+    }
+
+    pub fn is_imbalanced(&self, statistics: &PersonStatistics, threshold: usize) -> bool {
+        if !statistics.assignable {
+            return false;
+        }
+        let assignable = self
+            .people
+            .iter()
+            .filter(|statistics| statistics.assignable);
+        let count = assignable.clone().count();
+        let total: usize = assignable.map(|statistics| statistics.turns).sum();
+        (statistics.turns * count).abs_diff(total) > threshold * count
+    }
+}
+
+pub fn turns_per_person(people: &People, affectations: &[Affectation]) -> Vec<(Person, usize)> {
+    Statistics::new(people, &[], affectations)
+        .people
+        .into_iter()
+        .map(|statistics| (statistics.person, statistics.turns))
+        .collect()
+}
+
+#[cfg(test)]
+mod statistics_should {
+    use super::*;
+    use crate::business::affectations::Assignee;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::{people, person};
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn affectation(date: &str, assignee: Assignee) -> Affectation {
+        Affectation {
+            date: date_from(date),
+            assignee,
+        }
+    }
+
+    fn statistics() -> Statistics {
+        let xavier = || Assignee::Person(person(1, "Xavier"));
+        let merve = || Assignee::Person(person(2, "Merve"));
+        Statistics::new(
+            &people(&["Xavier", "Merve", "Jean"]),
+            &[],
+            &[
+                affectation("2025-01-06", xavier()),
+                affectation("2025-01-07", merve()),
+                affectation("2025-01-13", xavier()),
+                affectation("2025-01-31", xavier()),
+                affectation("2025-02-03", xavier()),
+            ],
+        )
+    }
+
+    #[rstest]
+    fn break_turns_down_by_weekday_and_month() {
+        let xavier = &statistics().people[0];
+        assert_that!(xavier.turns, eq(4));
+        assert_that!(xavier.per_weekday, eq([3, 0, 0, 0, 1, 0, 0]));
+        assert_that!(
+            xavier.per_month.iter().collect::<Vec<_>>(),
+            container_eq([(&(2025, 1), &3), (&(2025, 2), &1)])
+        )
+    }
+
+    #[rstest]
+    fn measure_gaps_between_turns() {
+        let statistics = statistics();
+        assert_that!(statistics.people[0].longest_gap, some(eq(18)));
+        assert_that!(statistics.people[0].shortest_gap, some(eq(3)));
+        assert_that!(statistics.people[1].longest_gap, none());
+    }
+
+    #[rstest]
+    fn list_the_months_with_turns() {
+        assert_that!(statistics().months(), container_eq([(2025, 1), (2025, 2)]))
+    }
+
+    #[rstest]
+    #[case::far_above_average(0, 1, true)]
+    #[case::far_below_average(2, 1, true)]
+    #[case::close_to_average(1, 1, false)]
+    #[case::within_a_larger_threshold(0, 3, false)]
+    fn flag_imbalances_beyond_a_threshold(
+        #[case] index: usize,
+        #[case] threshold: usize,
+        #[case] expected: bool,
+    ) {
+        let statistics = statistics();
+        assert_that!(
+            statistics.is_imbalanced(&statistics.people[index], threshold),
+            eq(expected)
+        )
+    }
+
+    #[rstest]
+    fn leave_people_outside_of_teams_out_of_the_balance() {
+        let team = Assignee::Group {
+            name: "Team Blue".into(),
+            members: vec![person(1, "Xavier"), person(2, "Merve")],
+        };
+        let statistics = Statistics::new(
+            &people(&["Xavier", "Merve", "Jean"]),
+            &[Group::new("Team Blue".into())
+                .toggle_member(1.into())
+                .toggle_member(2.into())],
+            &[
+                affectation("2025-01-06", team.clone()),
+                affectation("2025-01-07", team),
+            ],
+        );
+        assert_that!(
+            statistics
+                .people
+                .iter()
+                .map(|person| statistics.is_imbalanced(person, 0))
+                .collect::<Vec<_>>(),
+            container_eq([false, false, false])
+        );
+        assert_that!(statistics.people[2].assignable, is_false())
+    }
+
+    #[rstest]
+    fn count_the_turns_of_everybody() {
+        let affectations = [
+            affectation("2025-01-06", Assignee::Person(person(1, "Xavier"))),
+            affectation("2025-01-07", Assignee::Person(person(1, "Xavier"))),
+            affectation(
+                "2025-01-08",
+                Assignee::Group {
+                    name: "Team Blue".into(),
+                    members: vec![person(1, "Xavier"), person(2, "Merve")],
+                },
+            ),
+        ];
+        assert_that!(
+            turns_per_person(&people(&["Xavier", "Merve", "Jean"]), &affectations),
+            container_eq([
+                (person(1, "Xavier"), 3),
+                (person(2, "Merve"), 1),
+                (person(3, "Jean"), 0)
+            ])
+        )
+    }
+}
@@ -0,0 +1,235 @@
+use crate::business::affectations::Affectation;
+use crate::business::locale::Locale;
+use chrono::Datelike;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum TableGrouping {
+    #[default]
+    None,
+    Week,
+    Month,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct TableOptions {
+    pub grouping: TableGrouping,
+    pub locale: Locale,
+}
+
+type Row = [String; 3];
+
+pub fn export_markdown(affectations: &[Affectation], options: &TableOptions) -> String {
+    groups(affectations, options)
+        .into_iter()
+        .map(|(title, rows)| {
+            let heading = title.map_or(String::new(), |title| format!("### {title}\n\n"));
+            let lines: String = std::iter::once(header(options.locale))
+                .chain(std::iter::once(["---", "---", "---"].map(str::to_string)))
+                .chain(rows)
+                .map(|row| {
+                    format!(
+                        "| {} |\n",
+                        row.map(|cell| cell.replace('|', "\\|")).join(" | ")
+                    )
+                })
+                .collect();
+            heading + &lines
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn export_plain_text(affectations: &[Affectation], options: &TableOptions) -> String {
+    groups(affectations, options)
+        .into_iter()
+        .map(|(title, rows)| {
+            let heading = title.map_or(String::new(), |title| {
+                format!("{title}\n{}\n", "=".repeat(title.chars().count()))
+            });
+            let header = header(options.locale);
+            let widths = [0, 1, 2].map(|column| {
+                std::iter::once(&header)
+                    .chain(&rows)
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            });
+            let separator = widths.map(|width| "-".repeat(width));
+            let lines: String = std::iter::once(header.clone())
+                .chain(std::iter::once(separator))
+                .chain(rows)
+                .map(|row| {
+                    let line = row
+                        .iter()
+                        .zip(&widths)
+                        .map(|(cell, width)| format!("{cell:width$}"))
+                        .collect::<Vec<_>>()
+                        .join("  ");
+                    format!("{}\n", line.trim_end())
+                })
+                .collect();
+            heading + &lines
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn header(locale: Locale) -> Row {
+    match locale {
+        Locale::French => ["Date", "Jour", "Qui"],
+        Locale::English => ["Date", "Day", "Who"],
+    }
+    .map(str::to_string)
+}
+
+fn title(affectation: &Affectation, options: &TableOptions) -> Option<String> {
+    let date = affectation.date;
+    match options.grouping {
+        TableGrouping::None => None,
+        TableGrouping::Week => {
+            let week = date.iso_week();
+            Some(match options.locale {
+                Locale::French => format!("Semaine {} {}", week.week(), week.year()),
+                Locale::English => format!("Week {} {}", week.week(), week.year()),
+            })
+        }
+        TableGrouping::Month => Some(format!(
+            "{} {}",
+            options.locale.month_name(date.month()).unwrap_or_default(),
+            date.year()
+        )),
+    }
+}
+
+fn groups(affectations: &[Affectation], options: &TableOptions) -> Vec<(Option<String>, Vec<Row>)> {
+    let mut groups: Vec<(Option<String>, Vec<Row>)> = vec![];
+    for affectation in affectations {
+        let title = title(affectation, options);
+        let row = [
+            options.locale.format_date(affectation.date),
+            options
+                .locale
+                .weekday_name(affectation.date.weekday())
+                .to_string(),
+            affectation.assignee.to_string(),
+        ];
+        match groups.last_mut() {
+            Some((last, rows)) if *last == title => rows.push(row),
+            _ => groups.push((title, vec![row])),
+        }
+    }
+    if groups.is_empty() {
+        groups.push((None, vec![]));
+    }
+    groups
+}
+
+#[cfg(test)]
+mod table_should {
+    use super::*;
+    use crate::business::affectations::Assignee;
+    use crate::business::date_range::test_helpers::date_from;
+    use crate::business::person::test_helpers::person;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn affectations() -> Vec<Affectation> {
+        [
+            ("2025-01-31", 1, "Xavier"),
+            ("2025-02-03", 2, "Merve"),
+            ("2025-02-04", 3, "Jean-Baptiste"),
+        ]
+        .into_iter()
+        .map(|(date, id, name)| Affectation {
+            date: date_from(date),
+            assignee: Assignee::Person(person(id, name)),
+        })
+        .collect()
+    }
+
+    #[rstest]
+    fn export_a_markdown_table() {
+        assert_that!(
+            export_markdown(&affectations(), &TableOptions::default()),
+            eq("| Date | Jour | Qui |\n\
+                | --- | --- | --- |\n\
+                | 31/01/2025 | Vendredi | Xavier |\n\
+                | 03/02/2025 | Lundi | Merve |\n\
+                | 04/02/2025 | Mardi | Jean-Baptiste |\n")
+        )
+    }
+
+    #[rstest]
+    fn escape_pipes_in_markdown() {
+        let affectations = [Affectation {
+            date: date_from("2025-01-31"),
+            assignee: Assignee::Person(person(1, "A|B")),
+        }];
+        assert_that!(
+            export_markdown(&affectations, &TableOptions::default()),
+            contains_substring("| A\\|B |")
+        )
+    }
+
+    #[rstest]
+    fn export_an_aligned_plain_text_table_in_english() {
+        let options = TableOptions {
+            locale: Locale::English,
+            ..TableOptions::default()
+        };
+        assert_that!(
+            export_plain_text(&affectations(), &options),
+            eq("Date        Day      Who\n\
+                ----------  -------  -------------\n\
+                01/31/2025  Friday   Xavier\n\
+                02/03/2025  Monday   Merve\n\
+                02/04/2025  Tuesday  Jean-Baptiste\n")
+        )
+    }
+
+    #[rstest]
+    fn group_by_month() {
+        let options = TableOptions {
+            grouping: TableGrouping::Month,
+            ..TableOptions::default()
+        };
+        assert_that!(
+            export_markdown(&affectations(), &options),
+            eq("### Janvier 2025\n\n\
+                | Date | Jour | Qui |\n\
+                | --- | --- | --- |\n\
+                | 31/01/2025 | Vendredi | Xavier |\n\
+                \n\
+                ### Février 2025\n\n\
+                | Date | Jour | Qui |\n\
+                | --- | --- | --- |\n\
+                | 03/02/2025 | Lundi | Merve |\n\
+                | 04/02/2025 | Mardi | Jean-Baptiste |\n")
+        )
+    }
+
+    #[rstest]
+    fn group_by_week() {
+        let options = TableOptions {
+            grouping: TableGrouping::Week,
+            locale: Locale::English,
+        };
+        assert_that!(
+            export_plain_text(&affectations(), &options),
+            starts_with("Week 5 2025\n===========\n")
+        );
+        assert_that!(
+            export_plain_text(&affectations(), &options),
+            contains_substring("\nWeek 6 2025\n===========\n")
+        )
+    }
+
+    #[rstest]
+    fn export_only_the_header_without_affectations() {
+        assert_that!(
+            export_markdown(&[], &TableOptions::default()),
+            eq("| Date | Jour | Qui |\n| --- | --- | --- |\n")
+        )
+    }
+}
@@ -0,0 +1,465 @@
+use crate::business::date_range::DateRange;
+use crate::business::locale::Locale;
+use crate::business::schedule::ScheduleWarning;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Text {
+    Language,
+    Reset,
+    Undo,
+    Redo,
+    StorageRestoreFailed(String),
+    InvalidShareLink(String),
+    CopyShareLink,
+    LinkCopied,
+    Weekdays,
+    From,
+    To,
+    DatePlaceholder,
+    StartDateFormatWrong,
+    StartDateInvalid,
+    EndDateFormatWrong,
+    EndDateInvalid,
+    StartDateAfterEndDate,
+    Add,
+    Remove,
+    Import,
+    Cancel,
+    Exclude,
+    ExcludedPeriod(DateRange),
+    ExclusionReason,
+    Edit,
+    Apply,
+    OverlapsAnotherPeriod,
+    Merge,
+    Names,
+    AddSomebody,
+    MoveUp,
+    MoveDown,
+    DragToReorder,
+    StartHere,
+    AlreadyInTheList(String),
+    NameTooLong(usize),
+    NameControlCharacter,
+    EmptyName,
+    PasteList,
+    PasteListHint,
+    NamesAdded(usize),
+    NotAdded { entry: String, reason: String },
+    Color,
+    Email,
+    Phone,
+    InvalidEmail,
+    InvalidPhone,
+    Absence(DateRange),
+    AddAbsence,
+    Teams,
+    TeamName,
+    AddTeam,
+    AbsencePolicy,
+    SubstituteOnAbsence,
+    SkipOnAbsence,
+    TableFormat,
+    TableGrouping,
+    TableLanguage,
+    PlainText,
+    NoGrouping,
+    ByWeek,
+    ByMonth,
+    CopyTable,
+    TableCopied,
+    ShowList,
+    ShowCalendar,
+    Turns,
+    CalendarView,
+    Previous,
+    Next,
+    PickToSwap,
+    DragToReassign,
+    Month,
+    Week,
+    ImbalanceThreshold,
+    Name,
+    LongestGap,
+    ShortestGap,
+    Days(i64),
+    EventTitle,
+    Everybody,
+    Person,
+    DownloadIcs,
+    ImportAbsences,
+    ImportedPeriod { summary: String, period: DateRange },
+    NotImported { summary: String, error: String },
+    PartialDay,
+    ExcludedPeriods,
+    ImportInto,
+    AbsencesOf(String),
+    Delimiter,
+    Tabulation,
+    DateFormat,
+    IsoDatePattern,
+    DayFirstDatePattern,
+    MonthFirstDatePattern,
+    DownloadCsv,
+    ImportCsv,
+    DatesToPin(usize),
+    PinnedDates(usize),
+    UnpinAll,
+    HidePreview,
+    ShowPrintPreview,
+    PrintCalendar,
+    File,
+    Open,
+    Save,
+    SaveAs,
+    UnsavedChanges,
+    DiscardUnsavedChanges,
+    ProjectFile,
+    NewProject,
+    Modified,
+    CannotOpenOrSave(String),
+    RecentFiles,
+    ScheduleWarning(ScheduleWarning),
+}
+
+impl Locale {
+    pub fn text(&self, text: Text) -> String {
+        match self {
+            Locale::French => french(text, self),
+            Locale::English => english(text, self),
+        }
+    }
+}
+
+fn french(text: Text, locale: &Locale) -> String {
+    match text {
+        Text::Language => "Langue".into(),
+        Text::Reset => "Réinitialiser".into(),
+        Text::Undo => "Défaire".into(),
+        Text::Redo => "Refaire".into(),
+        Text::StorageRestoreFailed(error) => format!(
+            "La configuration enregistrée n'a pas pu être restaurée, une copie a été conservée : {error}"
+        ),
+        Text::InvalidShareLink(error) => format!("Le lien de partage est invalide : {error}"),
+        Text::CopyShareLink => "Copier le lien de partage".into(),
+        Text::LinkCopied => "Lien copié".into(),
+        Text::Weekdays => "Jours de la semaine".into(),
+        Text::From => "Du".into(),
+        Text::To => "Au".into(),
+        Text::DatePlaceholder => "jj/mm/aaaa".into(),
+        Text::StartDateFormatWrong => "Format de date de début non reconnu".into(),
+        Text::StartDateInvalid => "Date de début inexistante".into(),
+        Text::EndDateFormatWrong => "Format de date de fin non reconnu".into(),
+        Text::EndDateInvalid => "Date de fin inexistante".into(),
+        Text::StartDateAfterEndDate => "Date de début après la date de fin".into(),
+        Text::Add => "Ajouter".into(),
+        Text::Remove => "Supprimer".into(),
+        Text::Import => "Importer".into(),
+        Text::Cancel => "Annuler".into(),
+        Text::Exclude => "Exclure :".into(),
+        Text::ExcludedPeriod(period) => format!("sauf {}", french_period(period, locale)),
+        Text::ExclusionReason => "Motif (ex. Vacances de Noël)".into(),
+        Text::Edit => "Modifier".into(),
+        Text::Apply => "Appliquer".into(),
+        Text::OverlapsAnotherPeriod => "Chevauche une autre période".into(),
+        Text::Merge => "Fusionner".into(),
+        Text::Names => "Noms".into(),
+        Text::AddSomebody => "Ajouter quelqu'un".into(),
+        Text::MoveUp => "Monter".into(),
+        Text::MoveDown => "Descendre".into(),
+        Text::DragToReorder => "Glisser pour réordonner".into(),
+        Text::StartHere => "Commencer ici".into(),
+        Text::AlreadyInTheList(name) => format!("{name} est déjà dans la liste"),
+        Text::NameTooLong(max_length) => {
+            format!("Le nom ne doit pas dépasser {max_length} caractères")
+        }
+        Text::NameControlCharacter => "Le nom contient un caractère invalide".into(),
+        Text::EmptyName => "Le nom est vide".into(),
+        Text::PasteList => "Coller une liste".into(),
+        Text::PasteListHint => {
+            "Un nom par ligne, ou séparés par des virgules ou des points-virgules".into()
+        }
+        Text::NamesAdded(count) => format!("{count} noms ajoutés"),
+        Text::NotAdded { entry, reason } => format!("Non ajouté « {entry} » : {reason}"),
+        Text::Color => "Couleur".into(),
+        Text::Email => "Email".into(),
+        Text::Phone => "Téléphone".into(),
+        Text::InvalidEmail => "Email invalide".into(),
+        Text::InvalidPhone => "Numéro de téléphone invalide".into(),
+        Text::Absence(period) => format!("absent {}", french_period(period, locale)),
+        Text::AddAbsence => "Ajouter une absence".into(),
+        Text::Teams => "Équipes".into(),
+        Text::TeamName => "Nom de l'équipe".into(),
+        Text::AddTeam => "Ajouter une équipe".into(),
+        Text::AbsencePolicy => "En cas d'absence :".into(),
+        Text::SubstituteOnAbsence => "le suivant remplace, l'absent passe au tour d'après".into(),
+        Text::SkipOnAbsence => "l'absent perd son tour".into(),
+        Text::TableFormat => "Format du tableau".into(),
+        Text::TableGrouping => "Regroupement".into(),
+        Text::TableLanguage => "Langue du tableau".into(),
+        Text::PlainText => "Texte".into(),
+        Text::NoGrouping => "Sans regroupement".into(),
+        Text::ByWeek => "Par semaine".into(),
+        Text::ByMonth => "Par mois".into(),
+        Text::CopyTable => "Copier le tableau".into(),
+        Text::TableCopied => "Tableau copié".into(),
+        Text::ShowList => "Afficher la liste".into(),
+        Text::ShowCalendar => "Afficher le calendrier".into(),
+        Text::Turns => "Tours".into(),
+        Text::CalendarView => "Affichage".into(),
+        Text::Previous => "Précédent".into(),
+        Text::Next => "Suivant".into(),
+        Text::PickToSwap => {
+            "Entrée pour choisir ce tour, puis Entrée sur un autre jour pour l'y déplacer".into()
+        }
+        Text::DragToReassign => "Glisser sur un jour pour lui donner ce tour".into(),
+        Text::Month => "Mois".into(),
+        Text::Week => "Semaine".into(),
+        Text::ImbalanceThreshold => "Écart toléré".into(),
+        Text::Name => "Nom".into(),
+        Text::LongestGap => "Écart le plus long".into(),
+        Text::ShortestGap => "Écart le plus court".into(),
+        Text::Days(days) => format!("{days} j"),
+        Text::EventTitle => "Titre des évènements".into(),
+        Text::Everybody => "Tout le monde".into(),
+        Text::Person => "Personne".into(),
+        Text::DownloadIcs => "Télécharger le calendrier (.ics)".into(),
+        Text::ImportAbsences => "Importer des absences (.ics)".into(),
+        Text::ImportedPeriod { summary, period } => {
+            format!("{summary} : {}", french_period(period, locale))
+        }
+        Text::NotImported { summary, error } => format!("Non importé « {summary} » : {error}"),
+        Text::PartialDay => "(une partie de la journée seulement, exclue en entier)".into(),
+        Text::ExcludedPeriods => "Périodes exclues".into(),
+        Text::ImportInto => "Importer dans".into(),
+        Text::AbsencesOf(name) => format!("Absences de {name}"),
+        Text::Delimiter => "Séparateur".into(),
+        Text::Tabulation => "tabulation".into(),
+        Text::DateFormat => "Format des dates".into(),
+        Text::IsoDatePattern => "aaaa-mm-jj".into(),
+        Text::DayFirstDatePattern => "jj/mm/aaaa".into(),
+        Text::MonthFirstDatePattern => "mm/jj/aaaa".into(),
+        Text::DownloadCsv => "Télécharger le planning (.csv)".into(),
+        Text::ImportCsv => "Importer un planning (.csv)".into(),
+        Text::DatesToPin(count) => format!("{count} dates à épingler"),
+        Text::PinnedDates(count) => format!("{count} dates épinglées"),
+        Text::UnpinAll => "Tout désépingler".into(),
+        Text::HidePreview => "Masquer l'aperçu".into(),
+        Text::ShowPrintPreview => "Aperçu du calendrier imprimable".into(),
+        Text::PrintCalendar => "Imprimer le calendrier".into(),
+        Text::File => "Fichier".into(),
+        Text::Open => "Ouvrir…".into(),
+        Text::Save => "Enregistrer".into(),
+        Text::SaveAs => "Enregistrer sous…".into(),
+        Text::UnsavedChanges => "Modifications non enregistrées".into(),
+        Text::DiscardUnsavedChanges => {
+            "Les modifications non enregistrées seront perdues. Continuer ?".into()
+        }
+        Text::ProjectFile => "Projet".into(),
+        Text::NewProject => "Nouveau projet".into(),
+        Text::Modified => "(modifié)".into(),
+        Text::CannotOpenOrSave(error) => format!("Impossible d'ouvrir ou d'enregistrer {error}"),
+        Text::RecentFiles => "Fichiers récents".into(),
+        Text::ScheduleWarning(warning) => match warning {
+            ScheduleWarning::MissingRange => "Aucune période à planifier".into(),
+            ScheduleWarning::NobodyToAssign => "Personne à qui attribuer les tours".into(),
+            ScheduleWarning::EmptyGroup(name) => format!("L'équipe {name} n'a aucun membre"),
+            ScheduleWarning::NoDayLeft => {
+                "Aucun jour ne reste après le filtre des jours et les périodes exclues".into()
+            }
+            ScheduleWarning::UnassignedDays(count) => {
+                format!("Personne n'est disponible sur {count} jour(s)")
+            }
+            ScheduleWarning::IgnoredPins(count) => {
+                format!("{count} date(s) fixée(s) hors des jours planifiés ou attribuée(s) à une personne supprimée")
+            }
+        },
+    }
+}
+
+fn english(text: Text, locale: &Locale) -> String {
+    match text {
+        Text::Language => "Language".into(),
+        Text::Reset => "Reset".into(),
+        Text::Undo => "Undo".into(),
+        Text::Redo => "Redo".into(),
+        Text::StorageRestoreFailed(error) => {
+            format!("The saved configuration could not be restored, a copy has been kept: {error}")
+        }
+        Text::InvalidShareLink(error) => format!("The share link is invalid: {error}"),
+        Text::CopyShareLink => "Copy the share link".into(),
+        Text::LinkCopied => "Link copied".into(),
+        Text::Weekdays => "Days of the week".into(),
+        Text::From => "From".into(),
+        Text::To => "To".into(),
+        Text::DatePlaceholder => "mm/dd/yyyy".into(),
+        Text::StartDateFormatWrong => "Unrecognized start date format".into(),
+        Text::StartDateInvalid => "The start date does not exist".into(),
+        Text::EndDateFormatWrong => "Unrecognized end date format".into(),
+        Text::EndDateInvalid => "The end date does not exist".into(),
+        Text::StartDateAfterEndDate => "The start date is after the end date".into(),
+        Text::Add => "Add".into(),
+        Text::Remove => "Remove".into(),
+        Text::Import => "Import".into(),
+        Text::Cancel => "Cancel".into(),
+        Text::Exclude => "Exclude:".into(),
+        Text::ExcludedPeriod(period) => format!("except {}", english_period(period, locale)),
+        Text::ExclusionReason => "Reason (e.g. Christmas holidays)".into(),
+        Text::Edit => "Edit".into(),
+        Text::Apply => "Apply".into(),
+        Text::OverlapsAnotherPeriod => "Overlaps another period".into(),
+        Text::Merge => "Merge".into(),
+        Text::Names => "Names".into(),
+        Text::AddSomebody => "Add somebody".into(),
+        Text::MoveUp => "Move up".into(),
+        Text::MoveDown => "Move down".into(),
+        Text::DragToReorder => "Drag to reorder".into(),
+        Text::StartHere => "Start here".into(),
+        Text::AlreadyInTheList(name) => format!("{name} is already in the list"),
+        Text::NameTooLong(max_length) => {
+            format!("The name must not exceed {max_length} characters")
+        }
+        Text::NameControlCharacter => "The name contains an invalid character".into(),
+        Text::EmptyName => "The name is empty".into(),
+        Text::PasteList => "Paste a list".into(),
+        Text::PasteListHint => "One name per line, or separated by commas or semicolons".into(),
+        Text::NamesAdded(count) => format!("{count} names added"),
+        Text::NotAdded { entry, reason } => format!("Not added “{entry}”: {reason}"),
+        Text::Color => "Color".into(),
+        Text::Email => "Email".into(),
+        Text::Phone => "Phone".into(),
+        Text::InvalidEmail => "Invalid email".into(),
+        Text::InvalidPhone => "Invalid phone number".into(),
+        Text::Absence(period) => format!("away {}", english_period(period, locale)),
+        Text::AddAbsence => "Add an absence".into(),
+        Text::Teams => "Teams".into(),
+        Text::TeamName => "Team name".into(),
+        Text::AddTeam => "Add a team".into(),
+        Text::AbsencePolicy => "When somebody is away:".into(),
+        Text::SubstituteOnAbsence => {
+            "the next one stands in, the absent one takes the following turn".into()
+        }
+        Text::SkipOnAbsence => "the absent one loses their turn".into(),
+        Text::TableFormat => "Table format".into(),
+        Text::TableGrouping => "Grouping".into(),
+        Text::TableLanguage => "Table language".into(),
+        Text::PlainText => "Text".into(),
+        Text::NoGrouping => "No grouping".into(),
+        Text::ByWeek => "By week".into(),
+        Text::ByMonth => "By month".into(),
+        Text::CopyTable => "Copy the table".into(),
+        Text::TableCopied => "Table copied".into(),
+        Text::ShowList => "Show the list".into(),
+        Text::ShowCalendar => "Show the calendar".into(),
+        Text::Turns => "Turns".into(),
+        Text::CalendarView => "View".into(),
+        Text::Previous => "Previous".into(),
+        Text::Next => "Next".into(),
+        Text::PickToSwap => {
+            "Enter to pick this turn, then Enter on another day to move it there".into()
+        }
+        Text::DragToReassign => "Drag onto a day to give them that turn".into(),
+        Text::Month => "Month".into(),
+        Text::Week => "Week".into(),
+        Text::ImbalanceThreshold => "Tolerated difference".into(),
+        Text::Name => "Name".into(),
+        Text::LongestGap => "Longest gap".into(),
+        Text::ShortestGap => "Shortest gap".into(),
+        Text::Days(days) => format!("{days} d"),
+        Text::EventTitle => "Event title".into(),
+        Text::Everybody => "Everybody".into(),
+        Text::Person => "Person".into(),
+        Text::DownloadIcs => "Download the calendar (.ics)".into(),
+        Text::ImportAbsences => "Import absences (.ics)".into(),
+        Text::ImportedPeriod { summary, period } => {
+            format!("{summary}: {}", english_period(period, locale))
+        }
+        Text::NotImported { summary, error } => format!("Not imported “{summary}”: {error}"),
+        Text::PartialDay => "(only part of the day, excluded as a whole)".into(),
+        Text::ExcludedPeriods => "Excluded periods".into(),
+        Text::ImportInto => "Import into".into(),
+        Text::AbsencesOf(name) => format!("Absences of {name}"),
+        Text::Delimiter => "Delimiter".into(),
+        Text::Tabulation => "tab".into(),
+        Text::DateFormat => "Date format".into(),
+        Text::IsoDatePattern => "yyyy-mm-dd".into(),
+        Text::DayFirstDatePattern => "dd/mm/yyyy".into(),
+        Text::MonthFirstDatePattern => "mm/dd/yyyy".into(),
+        Text::DownloadCsv => "Download the schedule (.csv)".into(),
+        Text::ImportCsv => "Import a schedule (.csv)".into(),
+        Text::DatesToPin(count) => format!("{count} dates to pin"),
+        Text::PinnedDates(count) => format!("{count} pinned dates"),
+        Text::UnpinAll => "Unpin everything".into(),
+        Text::HidePreview => "Hide the preview".into(),
+        Text::ShowPrintPreview => "Preview the printable calendar".into(),
+        Text::PrintCalendar => "Print the calendar".into(),
+        Text::File => "File".into(),
+        Text::Open => "Open…".into(),
+        Text::Save => "Save".into(),
+        Text::SaveAs => "Save as…".into(),
+        Text::UnsavedChanges => "Unsaved changes".into(),
+        Text::DiscardUnsavedChanges => "Unsaved changes will be lost. Continue?".into(),
+        Text::ProjectFile => "Project".into(),
+        Text::NewProject => "New project".into(),
+        Text::Modified => "(modified)".into(),
+        Text::CannotOpenOrSave(error) => format!("Cannot open or save {error}"),
+        Text::RecentFiles => "Recent files".into(),
+        Text::ScheduleWarning(warning) => warning.to_string(),
+    }
+}
+
+fn french_period(period: DateRange, locale: &Locale) -> String {
+    format!(
+        "du {} au {}",
+        locale.format_date(period.starting_date()),
+        locale.format_date(period.ending_date())
+    )
+}
+
+fn english_period(period: DateRange, locale: &Locale) -> String {
+    format!(
+        "from {} to {}",
+        locale.format_date(period.starting_date()),
+        locale.format_date(period.ending_date())
+    )
+}
+
+#[cfg(test)]
+mod translations_should {
+    use super::*;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::french(Locale::French, "Réinitialiser")]
+    #[case::english(Locale::English, "Reset")]
+    fn translate_labels(#[case] locale: Locale, #[case] expected: &str) {
+        assert_that!(locale.text(Text::Reset), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, "Xavier est déjà dans la liste")]
+    #[case::english(Locale::English, "Xavier is already in the list")]
+    fn fill_in_parameters(#[case] locale: Locale, #[case] expected: &str) {
+        assert_that!(
+            locale.text(Text::AlreadyInTheList("Xavier".to_string())),
+            eq(expected)
+        )
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, "sauf du 10/03/2025 au 14/03/2025")]
+    #[case::english(Locale::English, "except from 03/10/2025 to 03/14/2025")]
+    fn format_periods_in_the_locale(#[case] locale: Locale, #[case] expected: &str) {
+        let period = DateRange::try_from(("2025-03-10", "2025-03-14")).unwrap();
+        assert_that!(locale.text(Text::ExcludedPeriod(period)), eq(expected))
+    }
+
+    #[rstest]
+    #[case::french(Locale::French, "jj/mm/aaaa")]
+    #[case::english(Locale::English, "mm/dd/yyyy")]
+    fn hint_the_date_format_of_the_locale(#[case] locale: Locale, #[case] expected: &str) {
+        assert_that!(locale.text(Text::DatePlaceholder), eq(expected))
+    }
+}
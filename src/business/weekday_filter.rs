@@ -1,8 +1,10 @@
 use chrono::Weekday::*;
 use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(from = "Vec<Weekday>", into = "Vec<Weekday>")]
 pub struct WeekDayFilter {
     accepted_days: HashSet<Weekday>,
 }
@@ -15,6 +17,22 @@ impl Default for WeekDayFilter {
     }
 }
 
+impl From<Vec<Weekday>> for WeekDayFilter {
+    fn from(value: Vec<Weekday>) -> Self {
+        WeekDayFilter {
+            accepted_days: HashSet::from_iter(value),
+        }
+    }
+}
+
+impl From<WeekDayFilter> for Vec<Weekday> {
+    fn from(value: WeekDayFilter) -> Self {
+        let mut days: Vec<Weekday> = value.accepted_days.into_iter().collect();
+        days.sort_by_key(Weekday::num_days_from_monday);
+        days
+    }
+}
+
 impl WeekDayFilter {
     pub fn accepted_days(&self) -> Vec<Weekday> {
         self.accepted_days.iter().copied().collect()
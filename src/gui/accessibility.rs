@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub fn use_unique_id(prefix: &str) -> String {
+    let prefix = prefix.to_string();
+    use_hook(move || format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+}
+
+pub fn focus(element: Signal<Option<Rc<MountedData>>>) {
+    if let Some(element) = element() {
+        spawn(async move {
+            let _ = element.set_focus(true).await;
+        });
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use dioxus::prelude::*;
+
+    pub fn render(root: fn() -> Element) -> String {
+        let mut dom = VirtualDom::new(root);
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+}
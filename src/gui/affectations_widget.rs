@@ -1,13 +1,197 @@
-use crate::business::affectations::Affectation;
+use crate::business::affectations::{Affectation, Assignee};
+use crate::business::calendar::calendar_months;
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::locale::Locale;
+use crate::business::overrides::Overrides;
+use crate::business::person::{People, Person};
+use crate::business::statistics::turns_per_person;
+use crate::business::table::{export_markdown, export_plain_text, TableGrouping, TableOptions};
+use crate::business::translations::Text;
+use crate::business::weekday_filter::WeekDayFilter;
+use crate::gui::calendar_widget::CalendarWidget;
+use crate::gui::language_widget::use_locale;
+use chrono::NaiveDate;
 use dioxus::prelude::*;
+use serde_json::json;
 
 #[component]
-pub fn AffectationsWidget(affectations: Vec<Affectation>) -> Element {
+pub fn AffectationsWidget(
+    affectations: Vec<Affectation>,
+    range: Option<DateRange>,
+    weekday_filter: WeekDayFilter,
+    excluded_period_filter: ExcludedPeriodsFilter,
+    people: People,
+    overrides: Signal<Overrides>,
+) -> Element {
+    let locale = use_locale()();
+    let mut calendar = use_signal(|| false);
+    let mut dragged: Signal<Option<NaiveDate>> = use_signal(|| None);
+    let mut dragged_person: Signal<Option<Person>> = use_signal(|| None);
+    let turns = turns_per_person(&people, &affectations);
+    let moved = affectations.clone();
+    let onmove = use_callback(move |(from, to): (NaiveDate, NaiveDate)| {
+        let swapped = overrides.peek().clone().swap(&moved, from, to);
+        overrides.set(swapped);
+    });
+    let reassigned = affectations.clone();
+    let onassign = use_callback(move |date: NaiveDate| {
+        if let Some(person) = dragged_person.take() {
+            let reassigned =
+                overrides
+                    .peek()
+                    .clone()
+                    .reassign(&reassigned, date, Assignee::Person(person));
+            overrides.set(reassigned);
+        }
+    });
+    let mut markdown = use_signal(|| true);
+    let mut options: Signal<TableOptions> = use_signal(|| TableOptions {
+        locale,
+        ..TableOptions::default()
+    });
+    let mut copied = use_signal(|| false);
+    let table = if markdown() {
+        export_markdown(&affectations, &options.read())
+    } else {
+        export_plain_text(&affectations, &options.read())
+    };
     rsx! {
-        for affectation in affectations {
-            div {
-                    {affectation.date.format("%d/%m/%Y").to_string()} " : {affectation.name}"
+        div{
+            select {
+                aria_label: locale.text(Text::TableFormat),
+                onchange: move |e| {
+                    markdown.set(e.value() == "markdown");
+                    copied.set(false);
+                },
+                option { value: "markdown", "Markdown" }
+                option { value: "text", {locale.text(Text::PlainText)} }
+            }
+            select {
+                aria_label: locale.text(Text::TableGrouping),
+                onchange: move |e| {
+                    let grouping = match e.value().as_str() {
+                        "week" => TableGrouping::Week,
+                        "month" => TableGrouping::Month,
+                        _ => TableGrouping::None,
+                    };
+                    options.with_mut(|o| o.grouping = grouping);
+                    copied.set(false);
+                },
+                option { value: "none", {locale.text(Text::NoGrouping)} }
+                option { value: "week", {locale.text(Text::ByWeek)} }
+                option { value: "month", {locale.text(Text::ByMonth)} }
+            }
+            select {
+                aria_label: locale.text(Text::TableLanguage),
+                onchange: move |e| {
+                    let table_locale = Locale::from_code(&e.value()).unwrap_or_default();
+                    options.with_mut(|o| o.locale = table_locale);
+                    copied.set(false);
+                },
+                for option in Locale::ALL {
+                    option {
+                        value: option.code(),
+                        selected: options.read().locale == option,
+                        {option.native_name()}
+                    }
+                }
+            }
+            button {
+                onclick: move |_| {
+                    document::eval(&format!("navigator.clipboard.writeText({});", json!(table)));
+                    copied.set(true);
+                },
+                {locale.text(Text::CopyTable)}
+            }
+            if copied() {
+                span{ role: "status", " " {locale.text(Text::TableCopied)}}
+            }
+        }
+        div{
+            button {
+                onclick: move |_| calendar.toggle(),
+                if calendar() { {locale.text(Text::ShowList)} } else { {locale.text(Text::ShowCalendar)} }
+            }
+        }
+        div{
+            {locale.text(Text::Turns)} " : "
+            for (i, (person, count)) in turns.into_iter().enumerate() {
+                if i > 0 {
+                    ", "
+                }
+                span {
+                    title: locale.text(Text::DragToReassign),
+                    draggable: true,
+                    ondragstart: move |_| dragged_person.set(Some(person.clone())),
+                    PersonLabel { person: person.clone() }
+                }
+                " {count}"
+            }
+        }
+        if let (true, Some(range)) = (calendar(), range) {
+            CalendarWidget {
+                months: calendar_months(range, &affectations, &weekday_filter, &excluded_period_filter, locale),
+                onmove,
+                onassign
+            }
+        } else {
+            for affectation in affectations {
+                div {
+                    tabindex: 0,
+                    title: locale.text(Text::PickToSwap),
+                    aria_grabbed: dragged() == Some(affectation.date),
+                    onkeydown: move |e| {
+                        if e.key() == Key::Enter {
+                            match dragged.take() {
+                                Some(from) => onmove.call((from, affectation.date)),
+                                None => dragged.set(Some(affectation.date)),
+                            }
+                        }
+                    },
+                    draggable: true,
+                    ondragstart: move |_| dragged.set(Some(affectation.date)),
+                    ondragover: move |e| e.prevent_default(),
+                    ondrop: move |e| {
+                        e.prevent_default();
+                        match dragged.take() {
+                            Some(from) => onmove.call((from, affectation.date)),
+                            None => onassign.call(affectation.date),
+                        }
+                    },
+                    {locale.format_date(affectation.date)} " : "
+                    AssigneeLabel { assignee: affectation.assignee }
+                }
             }
         }
     }
 }
+
+#[component]
+fn PersonLabel(person: Person) -> Element {
+    rsx! {
+        span {
+            style: person.color().map(|color| format!("color: {color}")),
+            "{person}"
+        }
+    }
+}
+
+#[component]
+pub fn AssigneeLabel(assignee: Assignee) -> Element {
+    match assignee {
+        Assignee::Person(person) => rsx! {
+            PersonLabel { person }
+        },
+        Assignee::Group { name, members } => rsx! {
+            "{name} ("
+            for (i, person) in members.into_iter().enumerate() {
+                if i > 0 {
+                    ", "
+                }
+                PersonLabel { person }
+            }
+            ")"
+        },
+    }
+}
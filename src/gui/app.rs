@@ -1,13 +1,33 @@
-use crate::business::affectations::create_affectations;
+use crate::business::affectations::AbsencePolicy;
 use crate::business::date_range::DateRange;
-use crate::business::excluded_period_filter::{ExcludePeriods, ExcludedPeriodsFilter};
-use crate::business::name::Names;
-use crate::business::weekday_filter::{FilterByWeekDays, WeekDayFilter};
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::group::Group;
+use crate::business::history::History;
+use crate::business::locale::Locale;
+use crate::business::overrides::Overrides;
+use crate::business::person::People;
+use crate::business::project::{Project, ScheduleId};
+use crate::business::schedule::Schedule;
+use crate::business::translations::Text;
+use crate::business::weekday_filter::WeekDayFilter;
 use crate::gui::affectations_widget::AffectationsWidget;
+use crate::gui::csv_widget::CsvWidget;
 use crate::gui::date_range_widget::DateRangeWidget;
 use crate::gui::excluded_periods::ExcludedPeriodsWidget;
+use crate::gui::groups_widget::GroupsWidget;
+use crate::gui::history_widget::HistoryWidget;
+use crate::gui::ics_export_widget::IcsExportWidget;
+use crate::gui::ics_import_widget::IcsImportWidget;
+use crate::gui::language_widget::LanguageWidget;
 use crate::gui::names_widget::NamesWidget;
+use crate::gui::print_calendar_widget::PrintCalendarWidget;
+#[cfg(feature = "desktop")]
+use crate::gui::project_file_widget::ProjectFileWidget;
+use crate::gui::share_link_widget::{shared_project, ShareLinkWidget};
+use crate::gui::statistics_widget::StatisticsWidget;
+use crate::gui::storage;
 use crate::gui::weekday_filter_widget::WeekDayFilterWidget;
+use chrono::Utc;
 use dioxus::prelude::*;
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
@@ -15,52 +35,203 @@ const MAIN_CSS: Asset = asset!("/assets/main.css");
 
 #[component]
 pub fn App() -> Element {
+    let locale = use_context_provider(|| Signal::new(Locale::default()));
+    let mut schedule_id: Signal<ScheduleId> = use_signal(ScheduleId::default);
     let mut range: Signal<Option<DateRange>> = use_signal(|| None);
-    let mut names: Signal<Names> = use_signal(Vec::default);
-    let weekday_filter: Signal<WeekDayFilter> = use_signal(WeekDayFilter::default);
-    let excluded_period_filter: Signal<ExcludedPeriodsFilter> =
+    let mut people: Signal<People> = use_signal(People::default);
+    let mut weekday_filter: Signal<WeekDayFilter> = use_signal(WeekDayFilter::default);
+    let mut excluded_period_filter: Signal<ExcludedPeriodsFilter> =
         use_signal(ExcludedPeriodsFilter::default);
-    let affectations = use_memo(move || {
-        if let Some(range) = range() {
-            create_affectations(
-                names(),
-                range
-                    .into_iter()
-                    .filter_by_weekday(&weekday_filter())
-                    .exclude_period(&excluded_period_filter()),
-            )
-        } else {
-            vec![]
+    let mut groups: Signal<Vec<Group>> = use_signal(Vec::default);
+    let mut absence_policy: Signal<AbsencePolicy> = use_signal(AbsencePolicy::default);
+    let mut overrides: Signal<Overrides> = use_signal(Overrides::default);
+    let settings = use_memo(move || Project {
+        id: schedule_id(),
+        people: people(),
+        groups: groups(),
+        range: range(),
+        weekday_filter: weekday_filter(),
+        excluded_periods: excluded_period_filter(),
+        absence_policy: absence_policy(),
+        overrides: overrides(),
+        affectations: vec![],
+    });
+    let plan = use_memo(move || Schedule::from(&*settings.read()).plan());
+    let affectations = use_memo(move || plan.read().affectations.clone());
+
+    let mut history = use_signal(|| History::new(Project::default()));
+    let mut restored = use_signal(|| false);
+    let mut storage_error: Signal<Option<String>> = use_signal(|| None);
+    let mut link_error: Signal<Option<String>> = use_signal(|| None);
+    let mut restore = move |project: Project| {
+        schedule_id.set(project.id.or_new(Utc::now()));
+        range.set(project.range);
+        people.set(project.people);
+        weekday_filter.set(project.weekday_filter);
+        excluded_period_filter.set(project.excluded_periods);
+        groups.set(project.groups);
+        absence_policy.set(project.absence_policy);
+        overrides.set(project.overrides);
+    };
+    use_future(move || async move {
+        let mut initial = Project::default();
+        match storage::load().await {
+            Ok(Some(project)) => initial = project,
+            Ok(None) => {}
+            Err(error) => storage_error.set(Some(error.to_string())),
+        }
+        match shared_project().await {
+            Some(Ok(project)) => initial = project,
+            Some(Err(error)) => link_error.set(Some(error.to_string())),
+            None => {}
+        }
+        let initial = Project {
+            affectations: vec![],
+            ..initial
+        };
+        history.set(History::new(initial.clone()));
+        restore(initial);
+        restored.set(true);
+    });
+    use_effect(move || {
+        let settings = settings();
+        if restored() {
+            let recorded = history.peek().clone().record(settings);
+            history.set(recorded);
+        }
+    });
+    let project = use_memo(move || Project {
+        affectations: affectations(),
+        ..settings()
+    });
+    use_effect(move || {
+        let project = project.read();
+        if restored() {
+            storage::save(&project);
         }
     });
 
+    if !restored() {
+        return rsx! {};
+    }
+
+    #[cfg(feature = "desktop")]
+    let project_file = rsx! {
+        ProjectFileWidget {
+            project: project,
+            onopen: move |project| restore(project)
+        }
+    };
+    #[cfg(not(feature = "desktop"))]
+    let project_file = rsx! {};
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
-        h1{
-            "🦋 Taking turns ! 🦋"
-        }
-        DateRangeWidget {
-            onchange: move |new_range|{
-                range.set(new_range);
+        div{
+            class: "no-print",
+            h1{
+                "🦋 Taking turns ! 🦋"
+            }
+            LanguageWidget {}
+            {project_file}
+            if let Some(error) = storage_error() {
+                div{
+                    {locale().text(Text::StorageRestoreFailed(error))}
+                }
+            }
+            if let Some(error) = link_error() {
+                div{
+                    {locale().text(Text::InvalidShareLink(error))}
+                }
+            }
+            HistoryWidget {
+                history: history,
+                onrestore: restore
+            }
+            ShareLinkWidget {
+                project: project()
+            }
+            button {
+                onclick: move |_| {
+                    storage::clear();
+                    storage_error.set(None);
+                    link_error.set(None);
+                    restore(Project::default());
+                },
+                {locale().text(Text::Reset)}
+            }
+            DateRangeWidget {
+                range: range(),
+                onchange: move |new_range|{
+                    range.set(new_range);
+                }
             }
-        }
 
-        ExcludedPeriodsWidget {
-            excluded_period_filter:excluded_period_filter
-        }
+            ExcludedPeriodsWidget {
+                excluded_period_filter:excluded_period_filter
+            }
 
-        WeekDayFilterWidget{
-            weekday_filter: weekday_filter
-        }
+            IcsImportWidget {
+                range: range(),
+                excluded_period_filter: excluded_period_filter,
+                people: people
+            }
 
-        NamesWidget{
-            onchange: move |new_names|{
-                names.set(new_names)
+            WeekDayFilterWidget{
+                weekday_filter: weekday_filter
+            }
+
+            NamesWidget{
+                people: people,
+                onremove: move |id| {
+                    groups.with_mut(|groups| {
+                        *groups = groups.drain(..).map(|group| group.without_member(id)).collect();
+                    });
+                }
+            }
+            GroupsWidget{
+                people: people(),
+                groups: groups,
+                absence_policy: absence_policy
+            }
+            ul{
+                class: "warnings",
+                role: "status",
+                for warning in plan.read().warnings.clone() {
+                    li{ {locale().text(Text::ScheduleWarning(warning))} }
+                }
+            }
+            AffectationsWidget {
+                affectations: affectations.read().clone(),
+                range: range(),
+                weekday_filter: weekday_filter(),
+                excluded_period_filter: excluded_period_filter(),
+                people: people(),
+                overrides: overrides
+            }
+            StatisticsWidget {
+                people: people(),
+                groups: groups(),
+                affectations: affectations.read().clone()
+            }
+            IcsExportWidget {
+                schedule: schedule_id(),
+                affectations: affectations.read().clone(),
+                people: people()
+            }
+            CsvWidget {
+                affectations: affectations.read().clone(),
+                people: people(),
+                groups: groups(),
+                overrides: overrides
             }
         }
-        AffectationsWidget {
-            affectations: affectations.read().clone()
+        PrintCalendarWidget {
+            range: range(),
+            affectations: affectations.read().clone(),
+            weekday_filter: weekday_filter(),
+            excluded_period_filter: excluded_period_filter()
         }
     }
 }
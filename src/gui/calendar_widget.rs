@@ -0,0 +1,179 @@
+use crate::business::calendar::{CalendarDay, CalendarMonth, DayKind};
+use crate::business::translations::Text;
+use crate::gui::affectations_widget::AssigneeLabel;
+use crate::gui::language_widget::use_locale;
+use chrono::{Datelike, NaiveDate};
+use dioxus::prelude::*;
+
+fn day_class(day: &CalendarDay) -> String {
+    let kind = match day.kind {
+        DayKind::OutsideRange => "outside-range",
+        DayKind::FilteredOut => "filtered-out",
+        DayKind::Excluded => "excluded",
+        DayKind::Unassigned => "unassigned",
+        DayKind::Assigned(_) => "assigned",
+    };
+    if day.in_month {
+        kind.to_string()
+    } else {
+        format!("{kind} other-month")
+    }
+}
+
+#[component]
+pub fn CalendarGrid(
+    weeks: Vec<Vec<CalendarDay>>,
+    onmove: Option<EventHandler<(NaiveDate, NaiveDate)>>,
+    onassign: Option<EventHandler<NaiveDate>>,
+) -> Element {
+    let locale = use_locale()();
+    let mut dragged: Signal<Option<NaiveDate>> = use_signal(|| None);
+    rsx! {
+        table{
+            class: "calendar",
+            thead{
+                tr{
+                    for day in locale.weekdays() {
+                        th{ {locale.weekday_name(day)} }
+                    }
+                }
+            }
+            tbody{
+                for week in weeks {
+                    tr{
+                        for day in week {
+                            td{
+                                class: day_class(&day),
+                                tabindex: if onmove.is_some() && day.in_month { "0" },
+                                title: if onmove.is_some() && day.in_month { locale.text(Text::PickToSwap) },
+                                aria_grabbed: onmove.is_some() && dragged() == Some(day.date),
+                                onkeydown: move |e| {
+                                    if e.key() != Key::Enter || !day.in_month {
+                                        return;
+                                    }
+                                    if let Some(onmove) = onmove {
+                                        match dragged.take() {
+                                            Some(from) => onmove.call((from, day.date)),
+                                            None => dragged.set(Some(day.date)),
+                                        }
+                                    }
+                                },
+                                ondragover: move |e| e.prevent_default(),
+                                ondrop: move |e| {
+                                    e.prevent_default();
+                                    match (dragged.take(), onmove, onassign) {
+                                        (Some(from), Some(onmove), _) => onmove.call((from, day.date)),
+                                        (None, _, Some(onassign)) => onassign.call(day.date),
+                                        _ => {}
+                                    }
+                                },
+                                div{ class: "day-number", {day.date.day().to_string()} }
+                                if let DayKind::Assigned(assignee) = day.kind.clone() {
+                                    if day.in_month {
+                                        div{
+                                            draggable: onmove.is_some(),
+                                            ondragstart: move |_| dragged.set(Some(day.date)),
+                                            AssigneeLabel { assignee }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Span {
+    Month,
+    Week,
+}
+
+#[component]
+pub fn CalendarWidget(
+    months: Vec<CalendarMonth>,
+    onmove: Option<EventHandler<(NaiveDate, NaiveDate)>>,
+    onassign: Option<EventHandler<NaiveDate>>,
+) -> Element {
+    let locale = use_locale()();
+    let mut span = use_signal(|| Span::Month);
+    let mut page = use_signal(|| 0_usize);
+    let pages: Vec<(String, Vec<Vec<CalendarDay>>)> = match span() {
+        Span::Month => months
+            .into_iter()
+            .map(|month| {
+                (
+                    format!(
+                        "{} {}",
+                        locale.month_name(month.month).unwrap_or_default(),
+                        month.year
+                    ),
+                    month.weeks,
+                )
+            })
+            .collect(),
+        Span::Week => {
+            let mut weeks: Vec<Vec<CalendarDay>> = vec![];
+            for week in months.into_iter().flat_map(|month| month.weeks) {
+                let week: Vec<CalendarDay> = week
+                    .into_iter()
+                    .map(|day| CalendarDay {
+                        in_month: true,
+                        ..day
+                    })
+                    .collect();
+                if weeks.last().map(|last| last[0].date) != Some(week[0].date) {
+                    weeks.push(week);
+                }
+            }
+            weeks
+                .into_iter()
+                .map(|week| {
+                    (
+                        format!(
+                            "{} – {}",
+                            locale.format_date(week[0].date),
+                            locale.format_date(week[6].date)
+                        ),
+                        vec![week],
+                    )
+                })
+                .collect()
+        }
+    };
+    let count = pages.len();
+    let current = page().min(count.saturating_sub(1));
+    let Some((title, weeks)) = pages.into_iter().nth(current) else {
+        return rsx! {};
+    };
+    rsx! {
+        div{
+            select {
+                aria_label: locale.text(Text::CalendarView),
+                onchange: move |e| {
+                    span.set(if e.value() == "week" { Span::Week } else { Span::Month });
+                    page.set(0);
+                },
+                option { value: "month", {locale.text(Text::Month)} }
+                option { value: "week", {locale.text(Text::Week)} }
+            }
+            button {
+                aria_label: locale.text(Text::Previous),
+                disabled: current == 0,
+                onclick: move |_| page.set(current.saturating_sub(1)),
+                "◀"
+            }
+            span{ aria_live: "polite", " {title} " }
+            button {
+                aria_label: locale.text(Text::Next),
+                disabled: current + 1 >= count,
+                onclick: move |_| page.set(current + 1),
+                "▶"
+            }
+        }
+        CalendarGrid { weeks, onmove, onassign }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::business::affectations::Affectation;
+use crate::business::csv::{export_csv, import_csv, CsvImport, CsvOptions};
+use crate::business::group::Group;
+use crate::business::overrides::Overrides;
+use crate::business::person::People;
+use crate::business::translations::Text;
+use crate::gui::download::data_url;
+use crate::gui::language_widget::use_locale;
+use dioxus::core_macro::component;
+use dioxus::dioxus_core::Element;
+use dioxus::prelude::*;
+
+const DATE_FORMATS: [(&str, Text); 3] = [
+    ("%Y-%m-%d", Text::IsoDatePattern),
+    ("%d/%m/%Y", Text::DayFirstDatePattern),
+    ("%m/%d/%Y", Text::MonthFirstDatePattern),
+];
+
+#[component]
+pub fn CsvWidget(
+    affectations: Vec<Affectation>,
+    people: People,
+    groups: Vec<Group>,
+    overrides: Signal<Overrides>,
+) -> Element {
+    let locale = use_locale()();
+    let mut options: Signal<CsvOptions> = use_signal(CsvOptions::default);
+    let mut import: Signal<Option<CsvImport>> = use_signal(|| None);
+    let export = export_csv(&affectations, &options.read());
+    rsx! {
+        div{
+            label { for:"csv_delimiter", {locale.text(Text::Delimiter)}}
+            select {
+                id: "csv_delimiter",
+                onchange: move |e| {
+                    let delimiter = if e.value() == "tab" { '\t' } else { e.value().chars().next().unwrap_or(',') };
+                    options.with_mut(|o| o.delimiter = delimiter);
+                },
+                option { value: ",", "," }
+                option { value: ";", ";" }
+                option { value: "tab", {locale.text(Text::Tabulation)} }
+            }
+            label { for:"csv_date_format", {locale.text(Text::DateFormat)}}
+            select {
+                id: "csv_date_format",
+                onchange: move |e| options.with_mut(|o| o.date_format = e.value()),
+                for (format, label) in DATE_FORMATS {
+                    option { value: format, {locale.text(label)} }
+                }
+            }
+            match export {
+                Ok(csv) => rsx! {
+                    a {
+                        href: data_url("text/csv", &csv),
+                        download: "planning.csv",
+                        {locale.text(Text::DownloadCsv)}
+                    }
+                },
+                Err(error) => rsx! {
+                    div { role: "alert", "{error}" }
+                },
+            }
+        }
+        div{
+            label { for:"csv_import", {locale.text(Text::ImportCsv)}}
+            input {
+                type: "file",
+                id: "csv_import",
+                accept: ".csv,text/csv",
+                onchange: move |e| {
+                    let people = people.clone();
+                    let groups = groups.clone();
+                    async move {
+                        if let Some(files) = e.files() {
+                            for file in files.files() {
+                                if let Some(content) = files.read_file_to_string(&file).await {
+                                    import.set(Some(import_csv(&content, &options.read(), &people, &groups)));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(preview) = import() {
+            div{
+                {locale.text(Text::DatesToPin(preview.overrides.len()))}
+                for error in preview.errors.clone() {
+                    div { "{error}" }
+                }
+                button {
+                    disabled: preview.overrides.is_empty(),
+                    onclick: move |_| {
+                        let pinned = preview.overrides.iter().fold(overrides(), |pinned, (date, pin)| pinned.pin(*date, pin.clone()));
+                        overrides.set(pinned);
+                        import.set(None);
+                    },
+                    {locale.text(Text::Import)}
+                }
+                button {
+                    onclick: move |_| import.set(None),
+                    {locale.text(Text::Cancel)}
+                }
+            }
+        }
+        if !overrides.read().is_empty() {
+            div{
+                {locale.text(Text::PinnedDates(overrides.read().len()))}
+                " "
+                button {
+                    onclick: move |_| overrides.set(Overrides::default()),
+                    {locale.text(Text::UnpinAll)}
+                }
+            }
+        }
+    }
+}
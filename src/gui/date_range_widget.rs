@@ -1,4 +1,8 @@
 use crate::business::date_range::{DateRange, DateRangeError};
+use crate::business::locale::Locale;
+use crate::business::translations::Text;
+use crate::gui::accessibility::use_unique_id;
+use crate::gui::language_widget::use_locale;
 use chrono::Utc;
 use dioxus::core_macro::Props;
 use dioxus::dioxus_core::Element;
@@ -7,47 +11,179 @@ use dioxus::prelude::*;
 
 #[derive(PartialEq, Clone, Props)]
 pub struct DateRangeWidgetProps {
+    range: Option<DateRange>,
     onchange: EventHandler<Option<DateRange>>,
 }
 
 #[component]
 pub fn DateRangeWidget(props: DateRangeWidgetProps) -> Element {
-    let mut starting_date: Signal<String> = use_signal(|| Utc::now().date_naive().to_string());
-    let mut ending_date: Signal<String> = use_signal(|| Utc::now().date_naive().to_string());
-    let range = use_memo(move || (starting_date().as_str(), ending_date().as_str()).try_into());
+    let locale = use_locale();
+    let displayed = move |range: Option<DateRange>, locale: Locale| {
+        let today = locale.format_date(Utc::now().date_naive());
+        range.map_or((today.clone(), today), |range| {
+            (
+                locale.format_date(range.starting_date()),
+                locale.format_date(range.ending_date()),
+            )
+        })
+    };
+    let (start, end) = displayed(props.range, locale());
+    let mut starting_date: Signal<String> = use_signal(|| start);
+    let mut ending_date: Signal<String> = use_signal(|| end);
+    let mut typed_locale = use_signal(|| *locale.peek());
+    let range = use_memo(move || {
+        DateRange::parse(
+            starting_date().as_str(),
+            ending_date().as_str(),
+            typed_locale(),
+        )
+    });
     let range_option = use_memo(move || range.read().clone().ok());
     use_effect(move || props.onchange.call(*range_option.read()));
+    let range_prop = props.range;
+    use_effect(use_reactive!(|range_prop| {
+        if range_prop != *range_option.peek() {
+            let (start, end) = displayed(range_prop, *typed_locale.peek());
+            starting_date.set(start);
+            ending_date.set(end);
+        }
+    }));
+    use_effect(move || {
+        let locale = locale();
+        if locale != *typed_locale.peek() {
+            if let Some(range) = *range_option.peek() {
+                let (start, end) = displayed(Some(range), locale);
+                starting_date.set(start);
+                ending_date.set(end);
+            }
+            typed_locale.set(locale);
+        }
+    });
+    let locale = locale();
+    let start_id = use_unique_id("starting_date");
+    let end_id = use_unique_id("ending_date");
+    let range_id = use_unique_id("date_range");
+    let start_invalid = matches!(
+        *range.read(),
+        Err(DateRangeError::StartDateFormatWrong(_) | DateRangeError::StartDateInvalid(_, _))
+    );
+    let end_invalid = matches!(
+        *range.read(),
+        Err(DateRangeError::EndDateFormatWrong(_) | DateRangeError::EndDateInvalid(_, _))
+    );
     rsx! {
         div{
-            label { for:"starting_date", "Du"}
+            label { for:"{start_id}", {locale.text(Text::From)}}
             input {
-                type: "date",
-                id:"starting_date",
+                type: "text",
+                id:"{start_id}",
+                placeholder: locale.text(Text::DatePlaceholder),
+                aria_invalid: start_invalid,
+                aria_describedby: "{start_id}-error {range_id}-error",
                 value : "{starting_date}",
                 onchange: move |e| {
                     starting_date.set(e.value());
                 }
             }
-            if let Err(DateRangeError::StartDateFormatWrong(_)) = *range.read(){
-                div{"Date de début invalide"}
+            div{
+                id: "{start_id}-error",
+                aria_live: "polite",
+                if let Err(DateRangeError::StartDateFormatWrong(_)) = *range.read(){
+                    {locale.text(Text::StartDateFormatWrong)}
+                }
+                if let Err(DateRangeError::StartDateInvalid(_, _)) = *range.read(){
+                    {locale.text(Text::StartDateInvalid)}
+                }
             }
         }
         div{
-            label { for:"ending_date", "Au"}
+            label { for:"{end_id}", {locale.text(Text::To)}}
             input {
-                type: "date",
-                id:"ending_date",
+                type: "text",
+                id:"{end_id}",
+                placeholder: locale.text(Text::DatePlaceholder),
+                aria_invalid: end_invalid,
+                aria_describedby: "{end_id}-error {range_id}-error",
                 value : "{ending_date}",
                 onchange: move |e| {
                     ending_date.set(e.value());
                 }
             }
-            if let Err(DateRangeError::EndDateFormatWrong(_)) = *range.read(){
-                div{"Date de fin invalide"}
+            div{
+                id: "{end_id}-error",
+                aria_live: "polite",
+                if let Err(DateRangeError::EndDateFormatWrong(_)) = *range.read(){
+                    {locale.text(Text::EndDateFormatWrong)}
+                }
+                if let Err(DateRangeError::EndDateInvalid(_, _)) = *range.read(){
+                    {locale.text(Text::EndDateInvalid)}
+                }
+            }
+        }
+        div{
+            id: "{range_id}-error",
+            aria_live: "polite",
+            if let Err(DateRangeError::StartDateAfterEndDate(_,_)) = *range.read(){
+                {locale.text(Text::StartDateAfterEndDate)}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod date_range_widget_should {
+    use super::*;
+    use crate::gui::accessibility::test_helpers::render;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn widgets() -> Element {
+        use_context_provider(|| Signal::new(Locale::French));
+        rsx! {
+            DateRangeWidget { onchange: |_| {} }
+            DateRangeWidget {
+                range: DateRange::try_from(("2025-01-06", "2025-01-10")).ok(),
+                onchange: |_| {}
             }
         }
-        if let Err(DateRangeError::StartDateAfterEndDate(_,_)) = *range.read(){
-                div{"Date de début après la date de fin"}
+    }
+
+    fn ids(markup: &str, attribute: &str) -> Vec<String> {
+        markup
+            .split(&format!("{attribute}=\""))
+            .skip(1)
+            .filter_map(|rest| rest.split('"').next())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[rstest]
+    fn label_each_input_with_an_id_of_its_own() {
+        let markup = render(widgets);
+        let labelled = ids(&markup, "for");
+        assert_that!(labelled.len(), eq(4));
+        for id in &labelled {
+            assert_that!(markup, contains_substring(format!("id=\"{id}\"")));
+        }
+        let mut unique = labelled.clone();
+        unique.sort();
+        unique.dedup();
+        assert_that!(unique.len(), eq(labelled.len()))
+    }
+
+    #[rstest]
+    fn announce_errors_in_live_regions_described_by_the_inputs() {
+        let markup = render(widgets);
+        let described = ids(&markup, "aria-describedby");
+        assert_that!(described.len(), eq(4));
+        for described in described {
+            for id in described.split(' ') {
+                assert_that!(
+                    markup,
+                    contains_substring(format!("id=\"{id}\" aria-live=\"polite\""))
+                );
+            }
         }
     }
 }
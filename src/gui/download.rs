@@ -0,0 +1,12 @@
+pub fn data_url(mime_type: &str, content: &str) -> String {
+    let encoded: String = content
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect();
+    format!("data:{mime_type};charset=utf-8,{encoded}")
+}
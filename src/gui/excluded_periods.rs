@@ -1,49 +1,122 @@
 use crate::business::date_range::DateRange;
 use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::translations::Text;
+use crate::gui::accessibility::focus;
 use crate::gui::date_range_widget::DateRangeWidget;
+use crate::gui::language_widget::use_locale;
 use dioxus::core_macro::{component, Props};
 use dioxus::dioxus_core::Element;
 use dioxus::prelude::*;
+use std::rc::Rc;
 
 #[component]
 pub fn ExcludedPeriodsWidget(excluded_period_filter: Signal<ExcludedPeriodsFilter>) -> Element {
+    let locale = use_locale()();
     let mut current_exclusion: Signal<Option<DateRange>> = use_signal(|| None);
-    let mut excluded: Signal<Vec<DateRange>> = use_signal(Vec::default);
-    use_effect(move || {
-        excluded_period_filter.set(ExcludedPeriodsFilter::from(excluded.read().clone()))
-    });
+    let mut add_button: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let excluded: Vec<DateRange> = excluded_period_filter.read().periods().copied().collect();
     rsx! {
-        for (i,range) in excluded.iter().enumerate() {
-            div {
-                {format!("sauf du {} au {}", range.starting_date().format("%d/%m/%Y"), range.ending_date().format("%d/%m/%Y"))}
-                " "
-                button {
-                onclick:  move |_| {
-                    excluded.with_mut(move |v| v.remove(i));
-                },
-                "Supprimer"
+        fieldset{
+            legend{ {locale.text(Text::ExcludedPeriods)} }
+            for range in excluded {
+                ExcludedPeriodWidget {
+                    key: "{range.starting_date()}-{range.ending_date()}",
+                    range: range,
+                    excluded_period_filter: excluded_period_filter,
+                    onremove: move |_| {
+                        excluded_period_filter.set(excluded_period_filter().remove(&range));
+                        focus(add_button);
+                    }
+                }
             }
+            div{
+                div{
+                    {locale.text(Text::Exclude)}
+                }
+                DateRangeWidget {
+                    onchange: move |new_range|{
+                        current_exclusion.set(new_range)
+                    }
+                }
+                button {
+                    onmounted: move |e| add_button.set(Some(e.data())),
+                    onclick: move |_| {
+                        if let Some(range) = *current_exclusion.read() {
+                            excluded_period_filter.set(excluded_period_filter().insert(range));
+                        }
+                    },
+                    {locale.text(Text::Add)}
+                }
             }
         }
-        div{
+    }
+}
 
-        div{
-            "Exclure :"
-        }
-        DateRangeWidget {
-            onchange: move |new_range|{
-                current_exclusion.set(new_range)
+#[component]
+fn ExcludedPeriodWidget(
+    range: DateRange,
+    excluded_period_filter: Signal<ExcludedPeriodsFilter>,
+    onremove: EventHandler<()>,
+) -> Element {
+    let locale = use_locale()();
+    let mut editing = use_signal(|| false);
+    let mut edited: Signal<Option<DateRange>> = use_signal(|| Some(range));
+    let label = excluded_period_filter
+        .read()
+        .label(&range)
+        .unwrap_or_default()
+        .to_string();
+    let overlaps = !excluded_period_filter.read().overlapping(&range).is_empty();
+    rsx! {
+        div {
+            {locale.text(Text::ExcludedPeriod(range))}
+            " "
+            input {
+                placeholder: locale.text(Text::ExclusionReason),
+                aria_label: locale.text(Text::ExclusionReason),
+                value: "{label}",
+                onchange: move |e| {
+                    excluded_period_filter.set(excluded_period_filter().with_label(&range, Some(e.value())));
+                }
+            }
+            " "
+            button {
+                aria_expanded: editing(),
+                onclick: move |_| editing.toggle(),
+                {locale.text(Text::Edit)}
             }
-        }
             button {
-                onclick: move |_| {
-                    if let Some(range) = *current_exclusion.read() {
-                    excluded.with_mut(move |v| v.push(range))
+                onclick: move |_| onremove.call(()),
+                {locale.text(Text::Remove)}
+            }
+            if overlaps {
+                div {
+                    role: "status",
+                    {locale.text(Text::OverlapsAnotherPeriod)}
+                    " "
+                    button {
+                        onclick: move |_| {
+                            excluded_period_filter.set(excluded_period_filter().merge(&range));
+                        },
+                        {locale.text(Text::Merge)}
                     }
-                },
-                "Ajouter"
+                }
+            }
+            if editing() {
+                DateRangeWidget {
+                    range: Some(range),
+                    onchange: move |new_range| edited.set(new_range)
+                }
+                button {
+                    disabled: edited().is_none(),
+                    onclick: move |_| {
+                        if let Some(new_range) = edited() {
+                            excluded_period_filter.set(excluded_period_filter().replace(&range, new_range));
+                        }
+                    },
+                    {locale.text(Text::Apply)}
+                }
             }
-
         }
     }
 }
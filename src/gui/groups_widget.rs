@@ -0,0 +1,91 @@
+use crate::business::affectations::AbsencePolicy;
+use crate::business::group::Group;
+use crate::business::name::Name;
+use crate::business::person::People;
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use dioxus::core_macro::component;
+use dioxus::dioxus_core::Element;
+use dioxus::prelude::*;
+
+#[component]
+pub fn GroupsWidget(
+    people: People,
+    groups: Signal<Vec<Group>>,
+    absence_policy: Signal<AbsencePolicy>,
+) -> Element {
+    let locale = use_locale()();
+    let new_group: Signal<String> = use_signal(String::default);
+    rsx! {
+        fieldset{
+            legend{
+                {locale.text(Text::Teams)}
+            }
+            for (i,group) in groups().into_iter().enumerate() {
+                div {
+                    input {
+                        aria_label: locale.text(Text::TeamName),
+                        value: "{group.name()}",
+                        onchange: move |e|{
+                            let name: Result<Name,_> = e.value().try_into();
+                            if let Ok(name) = name{
+                                groups.with_mut(|v|v[i] = v[i].clone().with_name(name));
+                            }else{
+                                groups.with_mut(|v|v.remove(i));
+                            }
+                        }
+                    }
+                    for person in people.clone() {
+                        label {
+                            input {
+                                type: "checkbox",
+                                checked: group.members().contains(&person.id()),
+                                onclick: move |_| {
+                                    let id = person.id();
+                                    groups.with_mut(|v|v[i] = v[i].clone().toggle_member(id));
+                                }
+                            }
+                            "{person}"
+                        }
+                    }
+                }
+            }
+            div{
+                input {
+                        placeholder: locale.text(Text::AddTeam),
+                        aria_label: locale.text(Text::AddTeam),
+                        value:"{new_group}",
+                        onchange: move |e|{
+                            let name: Result<Name,_> = e.value().try_into();
+                            if let Ok(name) = name {
+                                groups.push(Group::new(name));
+                            }
+                        }
+                }
+            }
+            div{
+                label { for:"absence_policy", {locale.text(Text::AbsencePolicy)}}
+                select {
+                    id: "absence_policy",
+                    onchange: move |e| {
+                        absence_policy.set(if e.value() == "skip" {
+                            AbsencePolicy::Skip
+                        } else {
+                            AbsencePolicy::Substitute
+                        });
+                    },
+                    option {
+                        value: "substitute",
+                        selected: absence_policy() == AbsencePolicy::Substitute,
+                        {locale.text(Text::SubstituteOnAbsence)}
+                    }
+                    option {
+                        value: "skip",
+                        selected: absence_policy() == AbsencePolicy::Skip,
+                        {locale.text(Text::SkipOnAbsence)}
+                    }
+                }
+            }
+        }
+    }
+}
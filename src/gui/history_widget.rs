@@ -0,0 +1,61 @@
+use crate::business::history::History;
+use crate::business::project::Project;
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use dioxus::prelude::*;
+
+const SHORTCUTS: &str = r#"
+document.addEventListener('keydown', (event) => {
+    const typing = event.target.matches && event.target.matches('textarea, input:not([type=checkbox]):not([type=color])');
+    if (!(event.ctrlKey || event.metaKey) || typing) {
+        return;
+    }
+    const key = event.key.toLowerCase();
+    if (key === 'z' && !event.shiftKey) {
+        event.preventDefault();
+        dioxus.send('undo');
+    } else if (key === 'y' || (key === 'z' && event.shiftKey)) {
+        event.preventDefault();
+        dioxus.send('redo');
+    }
+});
+"#;
+
+#[component]
+pub fn HistoryWidget(
+    history: Signal<History<Project>>,
+    onrestore: EventHandler<Project>,
+) -> Element {
+    let locale = use_locale()();
+    let mut travel = move |step: fn(History<Project>) -> History<Project>| {
+        let moved = step(history.peek().clone());
+        onrestore.call(moved.present().clone());
+        history.set(moved);
+    };
+    use_future(move || async move {
+        let mut shortcuts = document::eval(SHORTCUTS);
+        while let Ok(action) = shortcuts.recv::<String>().await {
+            match action.as_str() {
+                "undo" => travel(History::undo),
+                "redo" => travel(History::redo),
+                _ => {}
+            }
+        }
+    });
+    rsx! {
+        div{
+            button {
+                title: "Ctrl+Z",
+                disabled: !history.read().can_undo(),
+                onclick: move |_| travel(History::undo),
+                {locale.text(Text::Undo)}
+            }
+            button {
+                title: "Ctrl+Y",
+                disabled: !history.read().can_redo(),
+                onclick: move |_| travel(History::redo),
+                {locale.text(Text::Redo)}
+            }
+        }
+    }
+}
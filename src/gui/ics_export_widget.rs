@@ -0,0 +1,59 @@
+use crate::business::affectations::Affectation;
+use crate::business::ics::{export_ics, IcsExportOptions};
+use crate::business::person::{People, PersonId};
+use crate::business::project::ScheduleId;
+use crate::business::translations::Text;
+use crate::gui::download::data_url;
+use crate::gui::language_widget::use_locale;
+use chrono::Utc;
+use dioxus::core_macro::component;
+use dioxus::dioxus_core::Element;
+use dioxus::prelude::*;
+
+#[component]
+pub fn IcsExportWidget(
+    schedule: ScheduleId,
+    affectations: Vec<Affectation>,
+    people: People,
+) -> Element {
+    let locale = use_locale()();
+    let mut options: Signal<IcsExportOptions> = use_signal(IcsExportOptions::default);
+    let href = data_url(
+        "text/calendar",
+        &export_ics(&affectations, schedule, &options.read(), Utc::now()),
+    );
+    let file_name = match options.read().person.and_then(|id| people.get(id)) {
+        Some(person) => format!("planning-{person}.ics"),
+        None => "planning.ics".to_string(),
+    };
+    rsx! {
+        div{
+            label { for:"ics_summary", {locale.text(Text::EventTitle)}}
+            input {
+                id: "ics_summary",
+                value: "{options.read().summary_template}",
+                onchange: move |e| options.with_mut(|o| o.summary_template = e.value())
+            }
+            select {
+                aria_label: locale.text(Text::Person),
+                onchange: move |e| {
+                    let person = e.value().parse::<u64>().ok().map(PersonId::from);
+                    options.with_mut(|o| o.person = person);
+                },
+                option { value: "", {locale.text(Text::Everybody)} }
+                for person in people.clone() {
+                    option {
+                        value: "{person.id()}",
+                        selected: options.read().person == Some(person.id()),
+                        "{person}"
+                    }
+                }
+            }
+            a {
+                href: "{href}",
+                download: "{file_name}",
+                {locale.text(Text::DownloadIcs)}
+            }
+        }
+    }
+}
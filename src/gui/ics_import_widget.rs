@@ -0,0 +1,104 @@
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::ics::{import_ics, IcsImport};
+use crate::business::person::{People, PersonId};
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use chrono::{Days, Utc};
+use dioxus::core_macro::component;
+use dioxus::dioxus_core::Element;
+use dioxus::prelude::*;
+
+fn default_horizon() -> DateRange {
+    let today = Utc::now().date_naive();
+    let next_year = today.checked_add_days(Days::new(365)).unwrap_or(today);
+    DateRange::new(today, next_year).expect("Next year is after today")
+}
+
+#[component]
+pub fn IcsImportWidget(
+    range: Option<DateRange>,
+    excluded_period_filter: Signal<ExcludedPeriodsFilter>,
+    people: Signal<People>,
+) -> Element {
+    let mut import: Signal<Option<IcsImport>> = use_signal(|| None);
+    let mut target: Signal<Option<PersonId>> = use_signal(|| None);
+    let horizon = range.unwrap_or_else(default_horizon);
+    let locale = use_locale()();
+    rsx! {
+        div{
+            label { for:"ics_import", {locale.text(Text::ImportAbsences)}}
+            input {
+                type: "file",
+                id: "ics_import",
+                accept: ".ics,text/calendar",
+                onchange: move |e| async move {
+                    if let Some(files) = e.files() {
+                        for file in files.files() {
+                            if let Some(content) = files.read_file_to_string(&file).await {
+                                import.set(Some(import_ics(&content, &horizon)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(preview) = import() {
+            div{
+                for imported in preview.periods.clone() {
+                    div {
+                        {locale.text(Text::ImportedPeriod { summary: imported.summary, period: imported.period })}
+                        if imported.partial_day {
+                            " "
+                            {locale.text(Text::PartialDay)}
+                        }
+                    }
+                }
+                for rejected in preview.rejected.clone() {
+                    div {
+                        {locale.text(Text::NotImported { summary: rejected.summary, error: rejected.error.to_string() })}
+                    }
+                }
+                select {
+                    aria_label: locale.text(Text::ImportInto),
+                    onchange: move |e| target.set(e.value().parse::<u64>().ok().map(PersonId::from)),
+                    option { value: "", {locale.text(Text::ExcludedPeriods)} }
+                    for person in people() {
+                        option {
+                            value: "{person.id()}",
+                            selected: target() == Some(person.id()),
+                            {locale.text(Text::AbsencesOf(person.to_string()))}
+                        }
+                    }
+                }
+                button {
+                    disabled: preview.periods.is_empty(),
+                    onclick: move |_| {
+                        let periods = preview.periods.iter().map(|imported| imported.period);
+                        match target() {
+                            None => {
+                                let filter = periods.fold(excluded_period_filter(), ExcludedPeriodsFilter::insert);
+                                excluded_period_filter.set(filter);
+                            }
+                            Some(id) => {
+                                let person = people.read().get(id).cloned();
+                                if let Some(person) = person {
+                                    let unavailability = periods.fold(person.unavailability().clone(), ExcludedPeriodsFilter::insert);
+                                    if let Ok(updated) = people().update(person.with_unavailability(unavailability)) {
+                                        people.set(updated);
+                                    }
+                                }
+                            }
+                        }
+                        import.set(None);
+                    },
+                    {locale.text(Text::Import)}
+                }
+                button {
+                    onclick: move |_| import.set(None),
+                    {locale.text(Text::Cancel)}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::business::locale::Locale;
+use crate::business::translations::Text;
+use dioxus::prelude::*;
+
+pub fn use_locale() -> Signal<Locale> {
+    use_context()
+}
+
+#[component]
+pub fn LanguageWidget() -> Element {
+    let mut locale = use_locale();
+    rsx! {
+        div{
+            label { for:"language", {locale().text(Text::Language)}}
+            select {
+                id: "language",
+                onchange: move |e| {
+                    if let Some(selected) = Locale::from_code(&e.value()) {
+                        locale.set(selected);
+                    }
+                },
+                for option in Locale::ALL {
+                    option {
+                        value: option.code(),
+                        selected: locale() == option,
+                        {option.native_name()}
+                    }
+                }
+            }
+        }
+    }
+}
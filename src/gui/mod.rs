@@ -1,6 +1,21 @@
+mod accessibility;
 mod affectations_widget;
 pub mod app;
+mod calendar_widget;
+mod csv_widget;
 mod date_range_widget;
+mod download;
 mod excluded_periods;
+mod groups_widget;
+mod history_widget;
+mod ics_export_widget;
+mod ics_import_widget;
+mod language_widget;
 mod names_widget;
+mod print_calendar_widget;
+#[cfg(feature = "desktop")]
+pub mod project_file_widget;
+mod share_link_widget;
+mod statistics_widget;
+mod storage;
 mod weekday_filter_widget;
@@ -1,48 +1,401 @@
-use crate::business::name::{Name, Names};
-use dioxus::core_macro::{component, Props};
+use crate::business::date_range::DateRange;
+use crate::business::locale::Locale;
+use crate::business::name::{Name, NameError};
+use crate::business::person::{
+    Color, Email, PastedNames, People, Person, PersonError, PersonId, Phone,
+};
+use crate::business::translations::Text;
+use crate::gui::accessibility::focus;
+use crate::gui::date_range_widget::DateRangeWidget;
+use crate::gui::language_widget::use_locale;
+use dioxus::core_macro::component;
 use dioxus::dioxus_core::Element;
 use dioxus::prelude::*;
+use std::rc::Rc;
 
-#[derive(PartialEq, Clone, Props)]
-pub struct NamesWidgetProps {
-    onchange: EventHandler<Names>,
+fn rejection(locale: Locale, error: &NameError) -> String {
+    locale.text(match error {
+        NameError::EmptyName => Text::EmptyName,
+        NameError::DuplicateName(name) => Text::AlreadyInTheList(name.to_string()),
+        NameError::TooLong { max_length, .. } => Text::NameTooLong(*max_length),
+        NameError::ControlCharacter(_) => Text::NameControlCharacter,
+    })
 }
 
 #[component]
-pub fn NamesWidget(props: NamesWidgetProps) -> Element {
-    let mut names: Signal<Vec<Name>> = use_signal(Vec::default);
+pub fn NamesWidget(people: Signal<People>, onremove: Option<EventHandler<PersonId>>) -> Element {
+    let locale = use_locale()();
+    let mut error: Signal<Option<NameError>> = use_signal(|| None);
     let new_name: Signal<String> = use_signal(String::default);
-    use_effect(move || props.onchange.call(names.read().clone()));
+    let mut dragged: Signal<Option<usize>> = use_signal(|| None);
+    let mut pasted_text: Signal<String> = use_signal(String::default);
+    let mut report: Signal<Option<PastedNames>> = use_signal(|| None);
+    let mut paste = move |text: String| {
+        let pasted = people().paste(&text);
+        error.set(None);
+        people.set(pasted.people.clone());
+        report.set(Some(pasted));
+    };
+    let mut add_input: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let count = people.read().len();
     rsx! {
-        div{
-            "Noms :"
-        }
-        for (i,name) in names().iter().enumerate() {
-            div {
+        fieldset{
+            legend{
+                {locale.text(Text::Names)}
+            }
+            for (i,person) in people().into_iter().enumerate() {
+                div {
+                    key: "{person.id()}",
+                    role: "group",
+                    aria_label: "{person}",
+                    ondragover: move |e| e.prevent_default(),
+                    ondrop: move |e| {
+                        e.prevent_default();
+                        if let Some(from) = dragged.take() {
+                            people.set(people().move_to(from, i));
+                        }
+                    },
+                    span {
+                        class: "drag-handle",
+                        aria_hidden: true,
+                        draggable: true,
+                        title: locale.text(Text::DragToReorder),
+                        ondragstart: move |_| dragged.set(Some(i)),
+                        "☰"
+                    }
+                    button {
+                        title: locale.text(Text::MoveUp),
+                        aria_label: locale.text(Text::MoveUp),
+                        disabled: i == 0,
+                        onclick: move |_| people.set(people().move_to(i, i.saturating_sub(1))),
+                        "▲"
+                    }
+                    button {
+                        title: locale.text(Text::MoveDown),
+                        aria_label: locale.text(Text::MoveDown),
+                        disabled: i + 1 >= count,
+                        onclick: move |_| people.set(people().move_to(i, i + 1)),
+                        "▼"
+                    }
+                    button {
+                        disabled: i == 0,
+                        onclick: move |_| people.set(people().start_with(i)),
+                        {locale.text(Text::StartHere)}
+                    }
+                    PersonWidget {
+                        person: person.clone(),
+                        onchange: move |person: Option<Person>|{
+                            if let Some(person) = person{
+                                match people().replace(i, person) {
+                                    Ok(updated) => {
+                                        error.set(None);
+                                        people.set(updated);
+                                    }
+                                    Err(e) => error.set(Some(e)),
+                                }
+                            }else{
+                                error.set(None);
+                                let removed = people.peek().iter().nth(i).map(Person::id);
+                                people.set(people().remove(i));
+                                if let (Some(id), Some(onremove)) = (removed, onremove) {
+                                    onremove.call(id);
+                                }
+                                focus(add_input);
+                            }
+                        }
+                    }
+                }
+            }
+            div{
                 input {
-                    value: "{name}",
-                    onchange: move |e|{
-                        let name: Result<Name,_> = e.value().try_into();
-                        if let Ok(name) = name{
-                            names.with_mut(|v|v[i] = name);
-                        }else{
-                            names.with_mut(|v|v.remove(i));
+                        placeholder: locale.text(Text::AddSomebody),
+                        aria_label: locale.text(Text::AddSomebody),
+                        onmounted: move |e| add_input.set(Some(e.data())),
+                        value:"{new_name}",
+                        onchange: move |e|{
+                            if e.value().contains([',', ';']) {
+                                paste(e.value());
+                                return;
+                            }
+                            report.set(None);
+                            let name: Result<Name,_> = e.value().try_into();
+                            let id = PersonId::next_available(&people.read());
+                            match name.and_then(|name| people().insert(Person::new(id, name))) {
+                                Ok(updated) => {
+                                    error.set(None);
+                                    people.set(updated);
+                                }
+                                Err(NameError::EmptyName) => error.set(None),
+                                Err(e) => error.set(Some(e)),
+                            }
                         }
+                }
+            }
+            details {
+                summary { {locale.text(Text::PasteList)} }
+                textarea {
+                    placeholder: locale.text(Text::PasteListHint),
+                    aria_label: locale.text(Text::PasteList),
+                    rows: 6,
+                    value: "{pasted_text}",
+                    oninput: move |e| pasted_text.set(e.value())
+                }
+                button {
+                    onclick: move |_| {
+                        paste(pasted_text());
+                        pasted_text.set(String::new());
+                    },
+                    {locale.text(Text::Add)}
+                }
+            }
+            div{
+                aria_live: "polite",
+                if let Some(pasted) = report() {
+                    div{ {locale.text(Text::NamesAdded(pasted.added))} }
+                    for rejected in pasted.rejected {
+                        div{ {locale.text(Text::NotAdded { reason: rejection(locale, &rejected.error), entry: rejected.entry })} }
                     }
                 }
+                if let Some(error) = error(){
+                    div{ {rejection(locale, &error)} }
+                }
             }
         }
-        div{
+    }
+}
+
+fn optional<T>(value: String) -> Result<Option<T>, PersonError>
+where
+    T: TryFrom<String, Error = PersonError>,
+{
+    if value.trim().is_empty() {
+        Ok(None)
+    } else {
+        T::try_from(value).map(Some)
+    }
+}
+
+#[component]
+fn PersonWidget(person: Person, onchange: EventHandler<Option<Person>>) -> Element {
+    let locale = use_locale()();
+    let mut error: Signal<Option<PersonError>> = use_signal(|| None);
+    let mut name_error: Signal<Option<NameError>> = use_signal(|| None);
+    let email = person.email().map(Email::to_string).unwrap_or_default();
+    let phone = person.phone().map(Phone::to_string).unwrap_or_default();
+    let color = person
+        .color()
+        .map(Color::to_string)
+        .unwrap_or("#ffffff".to_string());
+    let name_person = person.clone();
+    let email_person = person.clone();
+    let phone_person = person.clone();
+    let unavailability_person = person.clone();
+    rsx! {
+        div {
+            input {
+                aria_label: locale.text(Text::Name),
+                value: "{person.name()}",
+                onchange: move |e|{
+                    let name: Result<Name,_> = e.value().try_into();
+                    match name {
+                        Ok(name) => {
+                            name_error.set(None);
+                            onchange.call(Some(name_person.clone().with_name(name)));
+                        }
+                        Err(NameError::EmptyName) => onchange.call(None),
+                        Err(e) => name_error.set(Some(e)),
+                    }
+                }
+            }
+            input {
+                type: "color",
+                aria_label: locale.text(Text::Color),
+                value: "{color}",
+                onchange: move |e|{
+                    if let Ok(color) = Color::try_from(e.value()){
+                        onchange.call(Some(person.clone().with_color(Some(color))));
+                    }
+                }
+            }
             input {
-                    placeholder: "Ajouter quelqu'un",
-                    value:"{new_name}",
-                    onchange: move |e|{
-                        let name: Result<Name,_> = e.value().try_into();
-                        if let Ok(name) = name {
-                            names.push(name);
+                type: "email",
+                placeholder: locale.text(Text::Email),
+                aria_label: locale.text(Text::Email),
+                value: "{email}",
+                onchange: move |e|{
+                    match optional(e.value()) {
+                        Ok(email) => {
+                            error.set(None);
+                            onchange.call(Some(email_person.clone().with_email(email)));
                         }
+                        Err(e) => error.set(Some(e)),
                     }
+                }
+            }
+            input {
+                type: "tel",
+                placeholder: locale.text(Text::Phone),
+                aria_label: locale.text(Text::Phone),
+                value: "{phone}",
+                onchange: move |e|{
+                    match optional(e.value()) {
+                        Ok(phone) => {
+                            error.set(None);
+                            onchange.call(Some(phone_person.clone().with_phone(phone)));
+                        }
+                        Err(e) => error.set(Some(e)),
+                    }
+                }
+            }
+            div{
+                aria_live: "polite",
+                if let Some(error) = name_error(){
+                    div{ {rejection(locale, &error)} }
+                }
+                if let Some(PersonError::InvalidEmail(_)) = *error.read(){
+                    div{ {locale.text(Text::InvalidEmail)} }
+                }
+                if let Some(PersonError::InvalidPhone(_)) = *error.read(){
+                    div{ {locale.text(Text::InvalidPhone)} }
+                }
+            }
+            UnavailabilityWidget {
+                person: unavailability_person,
+                onchange: move |person| onchange.call(Some(person))
+            }
+        }
+    }
+}
+
+#[component]
+fn UnavailabilityWidget(person: Person, onchange: EventHandler<Person>) -> Element {
+    let locale = use_locale()();
+    let mut current_absence: Signal<Option<DateRange>> = use_signal(|| None);
+    let periods: Vec<DateRange> = person.unavailability().periods().copied().collect();
+    let removing_person = person.clone();
+    let mut add_absence: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    rsx! {
+        for range in periods {
+            div {
+                {locale.text(Text::Absence(range))}
+                " "
+                button {
+                    onclick: {
+                        let person = removing_person.clone();
+                        move |_| {
+                            let unavailability = person.unavailability().clone().remove(&range);
+                            onchange.call(person.clone().with_unavailability(unavailability));
+                            focus(add_absence);
+                        }
+                    },
+                    {locale.text(Text::Remove)}
+                }
             }
         }
+        details {
+            summary {
+                onmounted: move |e| add_absence.set(Some(e.data())),
+                {locale.text(Text::AddAbsence)}
+            }
+            DateRangeWidget {
+                onchange: move |new_range|{
+                    current_absence.set(new_range)
+                }
+            }
+            button {
+                onclick: move |_| {
+                    if let Some(range) = *current_absence.read() {
+                        let unavailability = person.unavailability().clone().insert(range);
+                        onchange.call(person.clone().with_unavailability(unavailability));
+                    }
+                },
+                {locale.text(Text::Add)}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod names_widget_should {
+    use super::*;
+    use crate::gui::accessibility::test_helpers::render;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn widget() -> Element {
+        use_context_provider(|| Signal::new(Locale::English));
+        let people = use_signal(|| People::default().paste("Xavier, Merve").people);
+        rsx! {
+            NamesWidget { people }
+        }
+    }
+
+    #[rstest]
+    fn group_the_names_in_a_fieldset() {
+        assert_that!(
+            render(widget),
+            starts_with("<fieldset><legend>Names</legend>")
+        )
+    }
+
+    #[rstest]
+    fn name_the_group_of_controls_of_each_person() {
+        let markup = render(widget);
+        assert_that!(
+            markup,
+            contains_substring(r#"role="group" aria-label="Xavier""#)
+        );
+        assert_that!(
+            markup,
+            contains_substring(r#"role="group" aria-label="Merve""#)
+        )
+    }
+
+    #[rstest]
+    #[case::name("Name")]
+    #[case::color("Color")]
+    #[case::email("Email")]
+    #[case::phone("Phone")]
+    #[case::new_name("Add somebody")]
+    #[case::pasted_list("Paste a list")]
+    fn label_every_field(#[case] label: &str) {
+        assert_that!(
+            render(widget),
+            contains_substring(format!(r#"aria-label="{label}""#))
+        )
+    }
+
+    #[rstest]
+    fn label_the_buttons_showing_only_an_arrow() {
+        let markup = render(widget);
+        assert_that!(
+            markup,
+            matches_regex(
+                r#"(?s).*<button title="Move up" aria-label="Move up"[^>]*>▲</button>.*"#
+            )
+        );
+        assert_that!(
+            markup,
+            matches_regex(
+                r#"(?s).*<button title="Move down" aria-label="Move down"[^>]*>▼</button>.*"#
+            )
+        )
+    }
+
+    #[rstest]
+    fn hide_the_drag_handle_from_screen_readers() {
+        assert_that!(
+            render(widget),
+            contains_substring(r#"class="drag-handle" aria-hidden=true"#)
+        )
+    }
+
+    #[rstest]
+    fn keep_a_live_region_for_errors() {
+        assert_that!(
+            render(widget),
+            contains_substring(r#"<div aria-live="polite">"#)
+        )
     }
 }
@@ -0,0 +1,56 @@
+use crate::business::affectations::Affectation;
+use crate::business::calendar::calendar_months;
+use crate::business::date_range::DateRange;
+use crate::business::excluded_period_filter::ExcludedPeriodsFilter;
+use crate::business::translations::Text;
+use crate::business::weekday_filter::WeekDayFilter;
+use crate::gui::calendar_widget::CalendarGrid;
+use crate::gui::language_widget::use_locale;
+use dioxus::prelude::*;
+
+#[component]
+pub fn PrintCalendarWidget(
+    range: Option<DateRange>,
+    affectations: Vec<Affectation>,
+    weekday_filter: WeekDayFilter,
+    excluded_period_filter: ExcludedPeriodsFilter,
+) -> Element {
+    let mut preview = use_signal(|| false);
+    let locale = use_locale()();
+    let months = range
+        .map(|range| {
+            calendar_months(
+                range,
+                &affectations,
+                &weekday_filter,
+                &excluded_period_filter,
+                locale,
+            )
+        })
+        .unwrap_or_default();
+    rsx! {
+        div{
+            class: "no-print",
+            button {
+                onclick: move |_| preview.toggle(),
+                if preview() { {locale.text(Text::HidePreview)} } else { {locale.text(Text::ShowPrintPreview)} }
+            }
+            button {
+                onclick: move |_| {
+                    document::eval("window.print();");
+                },
+                {locale.text(Text::PrintCalendar)}
+            }
+        }
+        div{
+            class: if preview() { "printable preview" } else { "printable" },
+            for month in months {
+                section{
+                    class: "calendar-page",
+                    h2{ {locale.month_name(month.month).unwrap_or_default()} " {month.year}" }
+                    CalendarGrid { weeks: month.weeks }
+                }
+            }
+        }
+    }
+}
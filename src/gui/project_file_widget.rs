@@ -0,0 +1,275 @@
+use crate::business::locale::Locale;
+use crate::business::project::Project;
+use crate::business::recent_files::RecentFiles;
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use dioxus::desktop::muda::accelerator::{Accelerator, Code, Modifiers};
+use dioxus::desktop::muda::{Menu, MenuItem, Submenu};
+use dioxus::desktop::tao::event::{Event, WindowEvent};
+use dioxus::desktop::{
+    use_muda_event_handler, use_wry_event_handler, window, Config, WindowCloseBehaviour,
+};
+use dioxus::prelude::*;
+use rfd::{AsyncFileDialog, AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+use std::fs;
+use std::path::PathBuf;
+
+const OPEN: &str = "open";
+const SAVE: &str = "save";
+const SAVE_AS: &str = "save_as";
+
+pub fn desktop_config() -> Config {
+    let locale = Locale::default();
+    let file = Submenu::with_items(
+        locale.text(Text::File),
+        true,
+        &[
+            &MenuItem::with_id(
+                OPEN,
+                locale.text(Text::Open),
+                true,
+                Some(shortcut(Code::KeyO)),
+            ),
+            &MenuItem::with_id(
+                SAVE,
+                locale.text(Text::Save),
+                true,
+                Some(shortcut(Code::KeyS)),
+            ),
+            &MenuItem::with_id(
+                SAVE_AS,
+                locale.text(Text::SaveAs),
+                true,
+                Some(Accelerator::new(
+                    Some(Modifiers::CONTROL | Modifiers::SHIFT),
+                    Code::KeyS,
+                )),
+            ),
+        ],
+    )
+    .expect("The file menu is well formed");
+    Config::new()
+        .with_menu(Menu::with_items(&[&file]).expect("The menu bar is well formed"))
+        .with_close_behaviour(WindowCloseBehaviour::LastWindowHides)
+}
+
+fn shortcut(key: Code) -> Accelerator {
+    Accelerator::new(Some(Modifiers::CONTROL), key)
+}
+
+fn recent_files_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("taking-turns").join("recent-files.json"))
+}
+
+fn load_recent_files() -> RecentFiles {
+    recent_files_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_recent_files(recent: &RecentFiles) {
+    if let Some(path) = recent_files_path() {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(path, serde_json::to_string(recent).unwrap_or_default());
+    }
+}
+
+fn settings(project: &Project) -> Project {
+    Project {
+        affectations: vec![],
+        ..project.clone()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ProjectFile {
+    project: Memo<Project>,
+    onopen: EventHandler<Project>,
+    path: Signal<Option<PathBuf>>,
+    saved: Signal<Option<Project>>,
+    recent: Signal<RecentFiles>,
+    error: Signal<Option<String>>,
+    locale: Signal<Locale>,
+}
+
+impl ProjectFile {
+    fn unsaved(&self) -> bool {
+        match &*self.saved.read() {
+            Some(saved) => settings(saved) != settings(&self.project.read()),
+            None => !self.project.read().people.is_empty(),
+        }
+    }
+
+    async fn confirm_discard(self) -> bool {
+        let locale = *self.locale.peek();
+        !self.unsaved()
+            || AsyncMessageDialog::new()
+                .set_level(MessageLevel::Warning)
+                .set_title(locale.text(Text::UnsavedChanges))
+                .set_description(locale.text(Text::DiscardUnsavedChanges))
+                .set_buttons(MessageButtons::YesNo)
+                .show()
+                .await
+                == MessageDialogResult::Yes
+    }
+
+    fn remember(mut self, file: PathBuf) {
+        let recent = self.recent.peek().clone().open(file.clone());
+        save_recent_files(&recent);
+        self.recent.set(recent);
+        self.path.set(Some(file));
+        self.error.set(None);
+    }
+
+    fn read(mut self, file: PathBuf) {
+        let opened = fs::read_to_string(&file)
+            .map_err(|error| error.to_string())
+            .and_then(|json| Project::from_json(&json).map_err(|error| error.to_string()));
+        match opened {
+            Ok(project) => {
+                self.onopen.call(project.clone());
+                self.saved.set(Some(project));
+                self.remember(file);
+            }
+            Err(error) => {
+                let recent = self.recent.peek().clone().forget(&file);
+                save_recent_files(&recent);
+                self.recent.set(recent);
+                self.error
+                    .set(Some(format!("{} : {error}", file.display())));
+            }
+        }
+    }
+
+    fn write(mut self, file: PathBuf) {
+        let project = self.project.peek().clone();
+        match fs::write(&file, project.to_json()) {
+            Ok(()) => {
+                self.saved.set(Some(project));
+                self.remember(file);
+            }
+            Err(error) => self
+                .error
+                .set(Some(format!("{} : {error}", file.display()))),
+        }
+    }
+
+    async fn open(self) {
+        if self.confirm_discard().await {
+            if let Some(file) = AsyncFileDialog::new()
+                .add_filter(self.locale.peek().text(Text::ProjectFile), &["json"])
+                .pick_file()
+                .await
+            {
+                self.read(file.path().to_path_buf());
+            }
+        }
+    }
+
+    async fn open_recent(self, file: PathBuf) {
+        if self.confirm_discard().await {
+            self.read(file);
+        }
+    }
+
+    async fn save(self) {
+        let path = self.path.peek().clone();
+        match path {
+            Some(file) => self.write(file),
+            None => self.save_as().await,
+        }
+    }
+
+    async fn save_as(self) {
+        if let Some(file) = AsyncFileDialog::new()
+            .add_filter(self.locale.peek().text(Text::ProjectFile), &["json"])
+            .set_file_name("planning.json")
+            .save_file()
+            .await
+        {
+            self.write(file.path().to_path_buf());
+        }
+    }
+
+    async fn close(self) {
+        if self.confirm_discard().await {
+            window().close();
+        } else {
+            window().set_visible(true);
+        }
+    }
+}
+
+#[component]
+pub fn ProjectFileWidget(project: Memo<Project>, onopen: EventHandler<Project>) -> Element {
+    let file = ProjectFile {
+        project,
+        onopen,
+        path: use_signal(|| None),
+        saved: use_signal(|| None),
+        recent: use_signal(load_recent_files),
+        error: use_signal(|| None),
+        locale: use_locale(),
+    };
+    let locale = file.locale.cloned();
+    use_muda_event_handler(move |event| {
+        if event.id == OPEN {
+            spawn(file.open());
+        } else if event.id == SAVE {
+            spawn(file.save());
+        } else if event.id == SAVE_AS {
+            spawn(file.save_as());
+        }
+    });
+    use_wry_event_handler(move |event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            ..
+        } = event
+        {
+            spawn(file.close());
+        }
+    });
+    let name = file
+        .path
+        .read()
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map_or(locale.text(Text::NewProject), |name| {
+            name.to_string_lossy().to_string()
+        });
+    rsx! {
+        div{
+            button { onclick: move |_| { spawn(file.open()); }, {locale.text(Text::Open)} }
+            button { onclick: move |_| { spawn(file.save()); }, {locale.text(Text::Save)} }
+            button { onclick: move |_| { spawn(file.save_as()); }, {locale.text(Text::SaveAs)} }
+            span{
+                " {name}"
+                if file.unsaved() {
+                    " " {locale.text(Text::Modified)}
+                }
+            }
+            if let Some(error) = file.error.cloned() {
+                div{ {locale.text(Text::CannotOpenOrSave(error))} }
+            }
+            if !file.recent.read().is_empty() {
+                div{
+                    {locale.text(Text::RecentFiles)}
+                    ul{
+                        for (recent, label) in file.recent.read().iter().map(|path| (path.clone(), path.display().to_string())) {
+                            li{
+                                button {
+                                    onclick: move |_| { spawn(file.open_recent(recent.clone())); },
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
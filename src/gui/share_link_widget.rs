@@ -0,0 +1,41 @@
+use crate::business::project::Project;
+use crate::business::share::{decode_fragment, encode_fragment, ShareError};
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use dioxus::prelude::*;
+use serde_json::{json, Value};
+
+pub async fn shared_project() -> Option<Result<Project, ShareError>> {
+    let fragment = document::eval("return window.location.hash;").await;
+    match fragment {
+        Ok(Value::String(fragment)) if fragment.len() > 1 => {
+            document::eval("history.replaceState(null, '', window.location.pathname + window.location.search);");
+            Some(decode_fragment(&fragment))
+        }
+        _ => None,
+    }
+}
+
+#[component]
+pub fn ShareLinkWidget(project: Project) -> Element {
+    let locale = use_locale();
+    let mut copied = use_signal(|| false);
+    let fragment = encode_fragment(&project);
+    rsx! {
+        div{
+            button {
+                onclick: move |_| {
+                    document::eval(&format!(
+                        "const url = window.location.origin + window.location.pathname + window.location.search + '#' + {}; navigator.clipboard.writeText(url);",
+                        json!(fragment)
+                    ));
+                    copied.set(true);
+                },
+                {locale().text(Text::CopyShareLink)}
+            }
+            if copied() {
+                span{ role: "status", " " {locale().text(Text::LinkCopied)}}
+            }
+        }
+    }
+}
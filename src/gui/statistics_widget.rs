@@ -0,0 +1,70 @@
+use crate::business::affectations::Affectation;
+use crate::business::group::Group;
+use crate::business::locale::Locale;
+use crate::business::person::People;
+use crate::business::statistics::Statistics;
+use crate::business::translations::Text;
+use crate::gui::language_widget::use_locale;
+use dioxus::prelude::*;
+
+fn days(gap: Option<i64>, locale: Locale) -> String {
+    gap.map_or("-".to_string(), |gap| locale.text(Text::Days(gap)))
+}
+
+#[component]
+pub fn StatisticsWidget(
+    people: People,
+    groups: Vec<Group>,
+    affectations: Vec<Affectation>,
+) -> Element {
+    let locale = use_locale()();
+    let mut threshold = use_signal(|| 1_usize);
+    let statistics = Statistics::new(&people, &groups, &affectations);
+    let months = statistics.months();
+    rsx! {
+        div{
+            label { for:"imbalance_threshold", {locale.text(Text::ImbalanceThreshold)}}
+            input {
+                type: "number",
+                id: "imbalance_threshold",
+                min: "0",
+                value: "{threshold}",
+                onchange: move |e| threshold.set(e.value().parse().unwrap_or(0))
+            }
+        }
+        table{
+            class: "statistics",
+            thead{
+                tr{
+                    th{ {locale.text(Text::Name)} }
+                    th{ {locale.text(Text::Turns)} }
+                    for day in locale.weekdays() {
+                        th{ {locale.weekday_name(day)} }
+                    }
+                    for (year, month) in months.iter().copied() {
+                        th{ {locale.month_name(month).unwrap_or_default()} " {year}" }
+                    }
+                    th{ {locale.text(Text::LongestGap)} }
+                    th{ {locale.text(Text::ShortestGap)} }
+                }
+            }
+            tbody{
+                for person in statistics.people.iter() {
+                    tr{
+                        class: if statistics.is_imbalanced(person, threshold()) { "imbalanced" },
+                        td{"{person.person}"}
+                        td{"{person.turns}"}
+                        for day in locale.weekdays() {
+                            td{ {person.per_weekday[day.num_days_from_monday() as usize].to_string()} }
+                        }
+                        for month in months.iter() {
+                            td{ {person.per_month.get(month).copied().unwrap_or(0).to_string()} }
+                        }
+                        td{ {days(person.longest_gap, locale)} }
+                        td{ {days(person.shortest_gap, locale)} }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::business::project::{Project, ProjectError};
+use dioxus::prelude::*;
+use serde_json::{json, Value};
+
+const KEY: &str = "taking-turns";
+
+pub async fn load() -> Result<Option<Project>, ProjectError> {
+    let stored = document::eval(&format!("return localStorage.getItem({});", json!(KEY))).await;
+    match stored {
+        Ok(Value::String(project)) => Project::from_json(&project).map(Some).inspect_err(|_| {
+            document::eval(&format!(
+                "localStorage.setItem({}, {});",
+                json!(format!("{KEY}.backup")),
+                json!(project)
+            ));
+        }),
+        _ => Ok(None),
+    }
+}
+
+pub fn save(project: &Project) {
+    document::eval(&format!(
+        "localStorage.setItem({}, {});",
+        json!(KEY),
+        json!(project.to_json())
+    ));
+}
+
+pub fn clear() {
+    document::eval(&format!("localStorage.removeItem({});", json!(KEY)));
+}
@@ -1,35 +1,63 @@
+use crate::business::translations::Text;
 use crate::business::weekday_filter::WeekDayFilter;
-use chrono::Weekday;
-use chrono::Weekday::*;
+use crate::gui::language_widget::use_locale;
 use dioxus::core_macro::component;
 use dioxus::dioxus_core::Element;
 use dioxus::prelude::*;
 
-fn to_french(day: Weekday) -> &'static str {
-    match day {
-        Mon => "Lundi",
-        Tue => "Mardi",
-        Wed => "Mercredi",
-        Thu => "Jeudi",
-        Fri => "Vendredi",
-        Sat => "Samedi",
-        Sun => "Dimanche",
-    }
-}
 #[component]
 pub fn WeekDayFilterWidget(weekday_filter: Signal<WeekDayFilter>) -> Element {
+    let locale = use_locale()();
     let filters = use_memo(move || weekday_filter().accepted_days());
-    let days = [Mon, Tue, Wed, Thu, Fri, Sat, Sun];
     rsx! {
-        for day in days{
-            div{
-                input {
-                    type: "checkbox",
-                    checked: filters.read().contains(&day),
-                    onclick: move |_| weekday_filter.set(weekday_filter().toggle(day))
+        fieldset{
+            legend{ {locale.text(Text::Weekdays)} }
+            for day in locale.weekdays(){
+                div{
+                    label{
+                        input {
+                            type: "checkbox",
+                            checked: filters.read().contains(&day),
+                            onclick: move |_| weekday_filter.set(weekday_filter().toggle(day))
+                        }
+                        {locale.weekday_name(day)}
+                    }
                 }
-                {to_french(day)}
             }
         }
     }
 }
+
+#[cfg(test)]
+mod weekday_filter_widget_should {
+    use super::*;
+    use crate::business::locale::Locale;
+    use crate::gui::accessibility::test_helpers::render;
+    use googletest::assert_that;
+    use googletest::matchers::*;
+    use rstest::rstest;
+
+    fn widget() -> Element {
+        use_context_provider(|| Signal::new(Locale::French));
+        let weekday_filter = use_signal(WeekDayFilter::default);
+        rsx! {
+            WeekDayFilterWidget { weekday_filter }
+        }
+    }
+
+    #[rstest]
+    fn group_the_days_in_a_fieldset() {
+        assert_that!(
+            render(widget),
+            starts_with("<fieldset><legend>Jours de la semaine</legend>")
+        )
+    }
+
+    #[rstest]
+    fn label_each_checkbox_with_its_day() {
+        assert_that!(
+            render(widget),
+            matches_regex(r#".*<label><input type="checkbox"[^>]*/>Lundi</label>.*"#)
+        )
+    }
+}
@@ -1,2 +1,3 @@
 pub mod business;
+#[cfg(feature = "gui")]
 pub mod gui;
@@ -1,4 +1,9 @@
 use taking_turns::gui::app::App;
 fn main() {
+    #[cfg(feature = "desktop")]
+    dioxus::LaunchBuilder::new()
+        .with_cfg(taking_turns::gui::project_file_widget::desktop_config())
+        .launch(App);
+    #[cfg(not(feature = "desktop"))]
     dioxus::launch(App);
 }